@@ -0,0 +1,291 @@
+use std::f32;
+use matrix::Matrix;
+use vector::{Vector, Vector3, Vector4};
+use super::{BoundingFrustum, BoundingOrientedBox, BoundingSphere, ContainmentType, PlaneIntersectionType};
+use super::{contains_corners, plane_dot_coord};
+
+/// Axis-aligned box.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BoundingBox {
+    pub center: Vector3,
+    /// Distance from the center to each face.
+    pub extents: Vector3,
+}
+
+fn abs(v: &Vector3) -> Vector3 {
+    Vector3 {
+        x: v.x.abs(),
+        y: v.y.abs(),
+        z: v.z.abs(),
+    }
+}
+
+fn any_greater(v1: &Vector3, v2: &Vector3) -> bool {
+    v1.x > v2.x || v1.y > v2.y || v1.z > v2.z
+}
+
+fn all_less_or_equal(v1: &Vector3, v2: &Vector3) -> bool {
+    v1.x <= v2.x && v1.y <= v2.y && v1.z <= v2.z
+}
+
+impl BoundingBox {
+    pub fn new(center: Vector3, extents: Vector3) -> Self {
+        BoundingBox {
+            center,
+            extents,
+        }
+    }
+
+    fn from_min_max(min: &Vector3, max: &Vector3) -> Self {
+        // Measured from the rounded center, so that min and max pass contains_point
+        let center = (*min + *max) * 0.5;
+        BoundingBox::new(center, (*max - center).max(&(center - *min)))
+    }
+
+    pub fn min(&self) -> Vector3 {
+        self.center - self.extents
+    }
+
+    pub fn max(&self) -> Vector3 {
+        self.center + self.extents
+    }
+
+    /// The box spanned by two opposite corners.
+    pub fn create_from_corners(pt1: &Vector3, pt2: &Vector3) -> Self {
+        BoundingBox::from_min_max(&pt1.min(pt2), &pt1.max(pt2))
+    }
+
+    pub fn create_from_points(points: &[Vector3]) -> Self {
+        assert!(!points.is_empty());
+
+        let mut min = points[0];
+        let mut max = points[0];
+        for p in points.iter() {
+            min = min.min(p);
+            max = max.max(p);
+        }
+        BoundingBox::from_min_max(&min, &max)
+    }
+
+    pub fn create_from_sphere(sphere: &BoundingSphere) -> Self {
+        BoundingBox::new(sphere.center, Vector3::replicate(sphere.radius))
+    }
+
+    pub fn create_merged(b1: &BoundingBox, b2: &BoundingBox) -> Self {
+        BoundingBox::from_min_max(&b1.min().min(&b2.min()), &b1.max().max(&b2.max()))
+    }
+
+    pub fn get_corners(&self) -> [Vector3; 8] {
+        let c = self.center;
+        let e = self.extents;
+        [
+            Vector3 { x: c.x - e.x, y: c.y - e.y, z: c.z + e.z },
+            Vector3 { x: c.x + e.x, y: c.y - e.y, z: c.z + e.z },
+            Vector3 { x: c.x + e.x, y: c.y + e.y, z: c.z + e.z },
+            Vector3 { x: c.x - e.x, y: c.y + e.y, z: c.z + e.z },
+            Vector3 { x: c.x - e.x, y: c.y - e.y, z: c.z - e.z },
+            Vector3 { x: c.x + e.x, y: c.y - e.y, z: c.z - e.z },
+            Vector3 { x: c.x + e.x, y: c.y + e.y, z: c.z - e.z },
+            Vector3 { x: c.x - e.x, y: c.y + e.y, z: c.z - e.z },
+        ]
+    }
+
+    /// The axis-aligned box enclosing the transformed corners.
+    pub fn transform(&self, m: &Matrix) -> Self {
+        let corners = self.get_corners();
+        let mut min = corners[0].transform(m);
+        let mut max = min;
+        for c in corners[1..].iter() {
+            let c = c.transform(m);
+            min = min.min(&c);
+            max = max.max(&c);
+        }
+        BoundingBox::from_min_max(&min, &max)
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        if all_less_or_equal(&abs(&(*point - self.center)), &self.extents) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Disjoint
+        }
+    }
+
+    pub fn contains_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> ContainmentType {
+        if !self.intersects_triangle(v0, v1, v2) {
+            return ContainmentType::Disjoint;
+        }
+
+        let inside = |v: &Vector3| self.contains_point(v) == ContainmentType::Contains;
+        if inside(v0) && inside(v1) && inside(v2) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Intersects
+        }
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        if !sphere.intersects_box(self) {
+            return ContainmentType::Disjoint;
+        }
+
+        let radius = Vector3::replicate(sphere.radius);
+        if all_less_or_equal(&(self.min() + radius), &sphere.center) && all_less_or_equal(&sphere.center, &(self.max() - radius)) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Intersects
+        }
+    }
+
+    pub fn contains_box(&self, b: &BoundingBox) -> ContainmentType {
+        if !self.intersects_box(b) {
+            return ContainmentType::Disjoint;
+        }
+
+        if all_less_or_equal(&self.min(), &b.min()) && all_less_or_equal(&b.max(), &self.max()) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Intersects
+        }
+    }
+
+    pub fn contains_oriented_box(&self, b: &BoundingOrientedBox) -> ContainmentType {
+        let inside = |c: &Vector3| self.contains_point(c) == ContainmentType::Contains;
+        contains_corners(&b.get_corners(), inside, || self.intersects_oriented_box(b))
+    }
+
+    pub fn contains_frustum(&self, fr: &BoundingFrustum) -> ContainmentType {
+        let inside = |c: &Vector3| self.contains_point(c) == ContainmentType::Contains;
+        contains_corners(&fr.get_corners(), inside, || self.intersects_frustum(fr))
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        sphere.intersects_box(self)
+    }
+
+    pub fn intersects_box(&self, b: &BoundingBox) -> bool {
+        !(any_greater(&self.min(), &b.max()) || any_greater(&b.min(), &self.max()))
+    }
+
+    pub fn intersects_oriented_box(&self, b: &BoundingOrientedBox) -> bool {
+        b.intersects_box(self)
+    }
+
+    pub fn intersects_frustum(&self, fr: &BoundingFrustum) -> bool {
+        fr.intersects_box(self)
+    }
+
+    /// Separating axis test with the box axes, the triangle normal and the nine cross products
+    /// of the box axes and the triangle edges.
+    pub fn intersects_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> bool {
+        let box_min = self.min();
+        let box_max = self.max();
+
+        // Test the axes of the box (in effect test the AAB against the minimal AAB around the triangle)
+        let tri_min = v0.min(v1).min(v2);
+        let tri_max = v0.max(v1).max(v2);
+        if any_greater(&tri_min, &box_max) || any_greater(&box_min, &tri_max) {
+            return false;
+        }
+
+        // Test the plane of the triangle, a degenerate triangle always passes
+        let normal = (*v1 - *v0).cross(&(*v2 - *v0));
+        let dist = normal.dot(v0);
+        let nearest = Vector3 {
+            x: if normal.x > 0.0 { box_min.x } else { box_max.x },
+            y: if normal.y > 0.0 { box_min.y } else { box_max.y },
+            z: if normal.z > 0.0 { box_min.z } else { box_max.z },
+        };
+        let farthest = Vector3 {
+            x: if normal.x > 0.0 { box_max.x } else { box_min.x },
+            y: if normal.y > 0.0 { box_max.y } else { box_min.y },
+            z: if normal.z > 0.0 { box_max.z } else { box_min.z },
+        };
+        if nearest.dot(&normal) > dist || farthest.dot(&normal) < dist {
+            return false;
+        }
+
+        // Move the box center to zero to simplify the following tests
+        let tv0 = *v0 - self.center;
+        let tv1 = *v1 - self.center;
+        let tv2 = *v2 - self.center;
+
+        // Test the edge/edge axes (3*3)
+        let edges = [tv1 - tv0, tv2 - tv1, tv0 - tv2];
+        let box_axes = [
+            Vector3 { x: 1.0, y: 0.0, z: 0.0 },
+            Vector3 { x: 0.0, y: 1.0, z: 0.0 },
+            Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+        ];
+        for box_axis in box_axes.iter() {
+            for edge in edges.iter() {
+                let axis = box_axis.cross(edge);
+
+                let p0 = tv0.dot(&axis);
+                let p1 = tv1.dot(&axis);
+                let p2 = tv2.dot(&axis);
+                let radius = self.extents.dot(&abs(&axis));
+
+                if p0.min(p1).min(p2) > radius || p0.max(p1).max(p2) < -radius {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// The plane (a, b, c, d) must be normalized.
+    pub fn intersects_plane(&self, plane: &Vector4) -> PlaneIntersectionType {
+        let dist = plane_dot_coord(plane, &self.center);
+
+        // Half the length of the projection of the box onto the plane normal
+        let normal = Vector3 {
+            x: plane.x,
+            y: plane.y,
+            z: plane.z,
+        };
+        let radius = self.extents.dot(&abs(&normal));
+
+        if dist > radius {
+            PlaneIntersectionType::Front
+        } else if dist < -radius {
+            PlaneIntersectionType::Back
+        } else {
+            PlaneIntersectionType::Intersecting
+        }
+    }
+
+    /// Distance along `direction` to where the ray enters the slabs of the box. As in
+    /// DirectXCollision it is negative when the ray starts inside the box.
+    pub fn intersects_ray(&self, origin: &Vector3, direction: &Vector3) -> Option<f32> {
+        // Adjust ray origin to be relative to center of the box
+        let t_origin = self.center - *origin;
+
+        let mut t_min = -f32::MAX;
+        let mut t_max = f32::MAX;
+        for i in 0..3 {
+            let o = t_origin[i];
+            let d = direction[i];
+            let e = self.extents[i];
+
+            if d.abs() <= 1e-20 {
+                // The ray is parallel to the slab, so the origin has to lie between its faces
+                if -e - o > 0.0 || -e + o > 0.0 {
+                    return None;
+                }
+            } else {
+                let t1 = (o - e) / d;
+                let t2 = (o + e) / d;
+                t_min = t_min.max(t1.min(t2));
+                t_max = t_max.min(t1.max(t2));
+            }
+        }
+
+        if t_min > t_max || t_max < 0.0 {
+            None
+        } else {
+            Some(t_min)
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use std::f32;
+use matrix::Matrix;
+use vector::{Vector, Vector3, Vector4};
+use super::{BoundingBox, BoundingOrientedBox, BoundingSphere, ContainmentType, PlaneIntersectionType};
+use super::{contains_corners, plane_dot_coord, point_on_line_segment_nearest_point, rotated_axes};
+
+/// Frustum with its apex at `origin`, looking down the local -z axis like the view space of
+/// `Matrix::perspective_fov`. The slopes are x/distance and y/distance of the side planes, and
+/// `near` and `far` are distances from the origin.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BoundingFrustum {
+    pub origin: Vector3,
+    pub orientation: Vector4,
+    pub right_slope: f32,
+    pub left_slope: f32,
+    pub top_slope: f32,
+    pub bottom_slope: f32,
+    pub near: f32,
+    pub far: f32,
+}
+
+// Pairs of corners (as returned by get_corners) joined by an edge
+const EDGES: [(usize, usize); 12] = [
+    (0, 1), (1, 2), (2, 3), (3, 0),
+    (4, 5), (5, 6), (6, 7), (7, 4),
+    (0, 4), (1, 5), (2, 6), (3, 7),
+];
+
+// On the inner side of every plane, with the planes facing outwards
+fn inside_planes(planes: &[Vector4; 6], point: &Vector3) -> bool {
+    planes.iter().all(|plane| plane_dot_coord(plane, point) <= 0.0)
+}
+
+fn plane_normal(plane: &Vector4) -> Vector3 {
+    Vector3 {
+        x: plane.x,
+        y: plane.y,
+        z: plane.z,
+    }
+}
+
+fn transform_plane(plane: &Vector4, orientation: &Vector4, origin: &Vector3) -> Vector4 {
+    let normal = plane_normal(plane);
+    let length = normal.length();
+    let normal = normal.rotate(orientation) * (1.0 / length);
+    Vector4 {
+        x: normal.x,
+        y: normal.y,
+        z: normal.z,
+        w: plane.w / length - normal.dot(origin),
+    }
+}
+
+fn project(axis: &Vector3, points: &[Vector3]) -> (f32, f32) {
+    let mut min = f32::MAX;
+    let mut max = -f32::MAX;
+    for p in points.iter() {
+        let d = p.dot(axis);
+        min = min.min(d);
+        max = max.max(d);
+    }
+    (min, max)
+}
+
+// Separating axis test of two convex polyhedra given by their vertices, face normals and edge
+// directions. Parallel edges give a zero axis, which never separates.
+fn convex_intersects(a_points: &[Vector3], a_normals: &[Vector3], a_edges: &[Vector3],
+                     b_points: &[Vector3], b_normals: &[Vector3], b_edges: &[Vector3]) -> bool {
+    let separated = |axis: &Vector3| {
+        let (a_min, a_max) = project(axis, a_points);
+        let (b_min, b_max) = project(axis, b_points);
+        a_min > b_max || b_min > a_max
+    };
+
+    if a_normals.iter().chain(b_normals.iter()).any(separated) {
+        return false;
+    }
+    for ea in a_edges.iter() {
+        for eb in b_edges.iter() {
+            if separated(&ea.cross(eb)) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+impl BoundingFrustum {
+    /// The view frustum of a perspective projection such as `Matrix::perspective_fov` or
+    /// `Matrix::perspective`. Left-handed projections and reversed depth are handled as well.
+    pub fn create_from_matrix(projection: &Matrix) -> Self {
+        let inverse = projection.inverse();
+        let unproject = |x: f32, y: f32, z: f32| {
+            let p = Vector4 { x, y, z, w: 1.0 }.transform(&inverse);
+            Vector3 { x: p.x / p.w, y: p.y / p.w, z: p.z / p.w }
+        };
+
+        let mut points = [
+            unproject(1.0, 0.0, 1.0),
+            unproject(-1.0, 0.0, 1.0),
+            unproject(0.0, 1.0, 1.0),
+            unproject(0.0, -1.0, 1.0),
+            unproject(0.0, 0.0, 0.0),
+            unproject(0.0, 0.0, 1.0),
+        ];
+
+        // A left-handed projection looks down +z, which is the local frame turned around y
+        let mut orientation = Vector4::quaternion_identity();
+        if points[4].z > 0.0 {
+            orientation = Vector4 { x: 0.0, y: 1.0, z: 0.0, w: 0.0 };
+            for p in points.iter_mut() {
+                *p = p.inverse_rotate(&orientation);
+            }
+        }
+
+        let x_slope = |p: &Vector3| p.x / -p.z;
+        let y_slope = |p: &Vector3| p.y / -p.z;
+        let (near, far) = (-points[4].z, -points[5].z);
+        BoundingFrustum {
+            origin: Vector3::zero(),
+            orientation,
+            right_slope: x_slope(&points[0]).max(x_slope(&points[1])),
+            left_slope: x_slope(&points[0]).min(x_slope(&points[1])),
+            top_slope: y_slope(&points[2]).max(y_slope(&points[3])),
+            bottom_slope: y_slope(&points[2]).min(y_slope(&points[3])),
+            near: near.min(far),
+            far: near.max(far),
+        }
+    }
+
+    /// The near, far, right, left, top and bottom planes, normalized and facing outwards.
+    pub fn get_planes(&self) -> [Vector4; 6] {
+        let plane = |x: f32, y: f32, z: f32, w: f32| {
+            transform_plane(&Vector4 { x, y, z, w }, &self.orientation, &self.origin)
+        };
+        [
+            plane(0.0, 0.0, 1.0, self.near),
+            plane(0.0, 0.0, -1.0, -self.far),
+            plane(1.0, 0.0, self.right_slope, 0.0),
+            plane(-1.0, 0.0, -self.left_slope, 0.0),
+            plane(0.0, 1.0, self.top_slope, 0.0),
+            plane(0.0, -1.0, -self.bottom_slope, 0.0),
+        ]
+    }
+
+    /// Left-top, right-top, right-bottom and left-bottom on the near plane, followed by the
+    /// same on the far plane.
+    pub fn get_corners(&self) -> [Vector3; 8] {
+        let corner = |x_slope: f32, y_slope: f32, distance: f32| {
+            let local = Vector3 { x: x_slope * distance, y: y_slope * distance, z: -distance };
+            local.rotate(&self.orientation) + self.origin
+        };
+        [
+            corner(self.left_slope, self.top_slope, self.near),
+            corner(self.right_slope, self.top_slope, self.near),
+            corner(self.right_slope, self.bottom_slope, self.near),
+            corner(self.left_slope, self.bottom_slope, self.near),
+            corner(self.left_slope, self.top_slope, self.far),
+            corner(self.right_slope, self.top_slope, self.far),
+            corner(self.right_slope, self.bottom_slope, self.far),
+            corner(self.left_slope, self.bottom_slope, self.far),
+        ]
+    }
+
+    /// `m` may contain a rotation, a translation and a uniform scale.
+    pub fn transform(&self, m: &Matrix) -> Self {
+        let row = |i: usize| Vector3 { x: m[i][0], y: m[i][1], z: m[i][2] };
+        let (x, y, z) = (row(0), row(1), row(2));
+
+        // Composite the frustum rotation and the transform rotation
+        let (nx, ny, nz) = (x.normalize(), y.normalize(), z.normalize());
+        let rotation = Vector4::quaternion_rotation_matrix(&Matrix::new(
+            nx.x, nx.y, nx.z, 0.0,
+            ny.x, ny.y, ny.z, 0.0,
+            nz.x, nz.y, nz.z, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ));
+
+        let scale = x.length_sq().max(y.length_sq()).max(z.length_sq()).sqrt();
+        BoundingFrustum {
+            origin: self.origin.transform(m),
+            orientation: self.orientation.quaternion_multiply(&rotation),
+            near: self.near * scale,
+            far: self.far * scale,
+            ..*self
+        }
+    }
+
+    // Face normals and edge directions for the separating axis tests
+    fn normals(&self) -> [Vector3; 6] {
+        let planes = self.get_planes();
+        let mut normals = [Vector3::zero(); 6];
+        for (n, p) in normals.iter_mut().zip(planes.iter()) {
+            *n = plane_normal(p);
+        }
+        normals
+    }
+
+    fn edges(corners: &[Vector3; 8]) -> [Vector3; 6] {
+        [
+            corners[1] - corners[0],
+            corners[0] - corners[3],
+            corners[4] - corners[0],
+            corners[5] - corners[1],
+            corners[6] - corners[2],
+            corners[7] - corners[3],
+        ]
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        if inside_planes(&self.get_planes(), point) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Disjoint
+        }
+    }
+
+    pub fn contains_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> ContainmentType {
+        let planes = self.get_planes();
+        contains_corners(&[*v0, *v1, *v2], |c| inside_planes(&planes, c), || self.intersects_triangle(v0, v1, v2))
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        let planes = self.get_planes();
+        if planes.iter().all(|plane| plane_dot_coord(plane, &sphere.center) <= -sphere.radius) {
+            ContainmentType::Contains
+        } else if self.intersects_sphere(sphere) {
+            ContainmentType::Intersects
+        } else {
+            ContainmentType::Disjoint
+        }
+    }
+
+    pub fn contains_box(&self, b: &BoundingBox) -> ContainmentType {
+        let planes = self.get_planes();
+        contains_corners(&b.get_corners(), |c| inside_planes(&planes, c), || self.intersects_box(b))
+    }
+
+    pub fn contains_oriented_box(&self, b: &BoundingOrientedBox) -> ContainmentType {
+        let planes = self.get_planes();
+        contains_corners(&b.get_corners(), |c| inside_planes(&planes, c), || self.intersects_oriented_box(b))
+    }
+
+    pub fn contains_frustum(&self, fr: &BoundingFrustum) -> ContainmentType {
+        let planes = self.get_planes();
+        contains_corners(&fr.get_corners(), |c| inside_planes(&planes, c), || self.intersects_frustum(fr))
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        let planes = self.get_planes();
+        let radius_sq = sphere.radius * sphere.radius;
+
+        let mut dists = [0.0; 6];
+        for (d, plane) in dists.iter_mut().zip(planes.iter()) {
+            *d = plane_dot_coord(plane, &sphere.center);
+            if *d > sphere.radius {
+                return false;
+            }
+        }
+        if dists.iter().all(|d| *d <= 0.0) {
+            return true;
+        }
+
+        // The nearest point of the frustum is either inside a face the center is in front of ...
+        for i in 0..6 {
+            if dists[i] > 0.0 {
+                let point = sphere.center - plane_normal(&planes[i]) * dists[i];
+                if (0..6).all(|j| j == i || plane_dot_coord(&planes[j], &point) <= 0.0) {
+                    return true;
+                }
+            }
+        }
+
+        // ... or on one of the edges
+        let corners = self.get_corners();
+        EDGES.iter().any(|&(i, j)| {
+            let point = point_on_line_segment_nearest_point(&corners[i], &corners[j], &sphere.center);
+            (point - sphere.center).length_sq() <= radius_sq
+        })
+    }
+
+    pub fn intersects_box(&self, b: &BoundingBox) -> bool {
+        self.intersects_oriented_box(&BoundingOrientedBox::create_from_bounding_box(b))
+    }
+
+    pub fn intersects_oriented_box(&self, b: &BoundingOrientedBox) -> bool {
+        let corners = self.get_corners();
+        let axes = rotated_axes(&b.orientation);
+        convex_intersects(&corners, &self.normals(), &BoundingFrustum::edges(&corners),
+                          &b.get_corners(), &axes, &axes)
+    }
+
+    pub fn intersects_frustum(&self, fr: &BoundingFrustum) -> bool {
+        let a_corners = self.get_corners();
+        let b_corners = fr.get_corners();
+        convex_intersects(&a_corners, &self.normals(), &BoundingFrustum::edges(&a_corners),
+                          &b_corners, &fr.normals(), &BoundingFrustum::edges(&b_corners))
+    }
+
+    pub fn intersects_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> bool {
+        let corners = self.get_corners();
+        let edges = [*v1 - *v0, *v2 - *v1, *v0 - *v2];
+        let normal = edges[0].cross(&edges[1]);
+        convex_intersects(&corners, &self.normals(), &BoundingFrustum::edges(&corners),
+                          &[*v0, *v1, *v2], &[normal], &edges)
+    }
+
+    /// The plane (a, b, c, d) must be normalized.
+    pub fn intersects_plane(&self, plane: &Vector4) -> PlaneIntersectionType {
+        let corners = self.get_corners();
+        if corners.iter().all(|c| plane_dot_coord(plane, c) > 0.0) {
+            PlaneIntersectionType::Front
+        } else if corners.iter().all(|c| plane_dot_coord(plane, c) < 0.0) {
+            PlaneIntersectionType::Back
+        } else {
+            PlaneIntersectionType::Intersecting
+        }
+    }
+
+    /// Distance along `direction` to where the ray enters the frustum. As in DirectXCollision
+    /// it is zero when the ray starts inside the frustum.
+    pub fn intersects_ray(&self, origin: &Vector3, direction: &Vector3) -> Option<f32> {
+        if self.contains_point(origin) == ContainmentType::Contains {
+            return Some(0.0);
+        }
+
+        let mut t_near = -f32::MAX;
+        let mut t_far = f32::MAX;
+        for plane in self.get_planes().iter() {
+            let dist = plane_dot_coord(plane, origin);
+            let d = plane_normal(plane).dot(direction);
+
+            if d.abs() <= 1e-20 {
+                // The ray is parallel to the plane, so the origin has to lie behind it
+                if dist > 0.0 {
+                    return None;
+                }
+            } else {
+                let t = -dist / d;
+                if d < 0.0 {
+                    t_near = t_near.max(t);
+                } else {
+                    t_far = t_far.min(t);
+                }
+            }
+        }
+
+        if t_near > t_far || t_far < 0.0 {
+            None
+        } else {
+            Some(t_near)
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use matrix::Matrix;
+use vector::{Vector, Vector3, Vector4};
+use super::{BoundingBox, BoundingFrustum, BoundingSphere, ContainmentType, PlaneIntersectionType};
+use super::{contains_corners, plane_dot_coord, rotated_axes};
+
+fn abs(v: &Vector3) -> Vector3 {
+    Vector3 {
+        x: v.x.abs(),
+        y: v.y.abs(),
+        z: v.z.abs(),
+    }
+}
+
+// Eigenvectors of a symmetric matrix as the columns of the result (cyclic Jacobi)
+fn eigenvectors(mut a: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    for _ in 0..32 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= 1e-12 * diagonal {
+            break;
+        }
+
+        for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+
+            // Rotation zeroing a[p][q]
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            let rotate = |x: &mut f32, y: &mut f32| {
+                let (x0, y0) = (*x, *y);
+                *x = c * x0 - s * y0;
+                *y = s * x0 + c * y0;
+            };
+
+            // Columns p and q of a and v, then rows p and q of a
+            for row in a.iter_mut().chain(v.iter_mut()) {
+                let (mut x, mut y) = (row[p], row[q]);
+                rotate(&mut x, &mut y);
+                row[p] = x;
+                row[q] = y;
+            }
+            let (mut ap, mut aq) = (a[p], a[q]);
+            for (x, y) in ap.iter_mut().zip(aq.iter_mut()) {
+                rotate(x, y);
+            }
+            a[p] = ap;
+            a[q] = aq;
+        }
+    }
+
+    v
+}
+
+/// Box rotated by the unit quaternion `orientation` around its center.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BoundingOrientedBox {
+    pub center: Vector3,
+    /// Distance from the center to each face.
+    pub extents: Vector3,
+    pub orientation: Vector4,
+}
+
+impl BoundingOrientedBox {
+    pub fn new(center: Vector3, extents: Vector3, orientation: Vector4) -> Self {
+        BoundingOrientedBox {
+            center,
+            extents,
+            orientation,
+        }
+    }
+
+    pub fn create_from_bounding_box(b: &BoundingBox) -> Self {
+        BoundingOrientedBox::new(b.center, b.extents, Vector4::quaternion_identity())
+    }
+
+    /// Fits the box to the principal axes of the points (the eigenvectors of their covariance
+    /// matrix).
+    pub fn create_from_points(points: &[Vector3]) -> Self {
+        assert!(!points.is_empty());
+
+        let mut centroid = Vector3::zero();
+        for p in points.iter() {
+            centroid += *p;
+        }
+        centroid *= 1.0 / points.len() as f32;
+
+        let mut covariance = [[0.0; 3]; 3];
+        for p in points.iter() {
+            let d = *p - centroid;
+            for i in 0..3 {
+                for j in 0..3 {
+                    covariance[i][j] += d[i] * d[j];
+                }
+            }
+        }
+
+        // Right-handed orthonormal basis, so that it is a rotation
+        let v = eigenvectors(covariance);
+        let x = Vector3 { x: v[0][0], y: v[1][0], z: v[2][0] }.normalize();
+        let y = Vector3 { x: v[0][1], y: v[1][1], z: v[2][1] };
+        let y = (y - x * x.dot(&y)).normalize();
+        let z = x.cross(&y);
+        let orientation = Vector4::quaternion_rotation_matrix(&Matrix::new(
+            x.x, x.y, x.z, 0.0,
+            y.x, y.y, y.z, 0.0,
+            z.x, z.y, z.z, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ));
+
+        // Axis-aligned box of the points in the frame of the box
+        let mut min = points[0].inverse_rotate(&orientation);
+        let mut max = min;
+        for p in points[1..].iter() {
+            let local = p.inverse_rotate(&orientation);
+            min = min.min(&local);
+            max = max.max(&local);
+        }
+
+        // Measure the extents the way contains_point does, so that rounding cannot leave a
+        // point outside
+        let mut b = BoundingOrientedBox::new(((min + max) * 0.5).rotate(&orientation), Vector3::zero(), orientation);
+        for p in points.iter() {
+            b.extents = b.extents.max(&abs(&b.local_point(p)));
+        }
+        b
+    }
+
+    // The same box in its own frame, where it is axis-aligned around the origin
+    fn local_box(&self) -> BoundingBox {
+        BoundingBox::new(Vector3::zero(), self.extents)
+    }
+
+    // A point in the frame of local_box
+    fn local_point(&self, point: &Vector3) -> Vector3 {
+        (*point - self.center).inverse_rotate(&self.orientation)
+    }
+
+    pub fn get_corners(&self) -> [Vector3; 8] {
+        let mut corners = self.local_box().get_corners();
+        for c in corners.iter_mut() {
+            *c = c.rotate(&self.orientation) + self.center;
+        }
+        corners
+    }
+
+    /// `m` may contain a rotation, a translation and a uniform scale.
+    pub fn transform(&self, m: &Matrix) -> Self {
+        let row = |i: usize| Vector3 { x: m[i][0], y: m[i][1], z: m[i][2] };
+        let (x, y, z) = (row(0), row(1), row(2));
+
+        // Composite the box rotation and the transform rotation
+        let (nx, ny, nz) = (x.normalize(), y.normalize(), z.normalize());
+        let rotation = Vector4::quaternion_rotation_matrix(&Matrix::new(
+            nx.x, nx.y, nx.z, 0.0,
+            ny.x, ny.y, ny.z, 0.0,
+            nz.x, nz.y, nz.z, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ));
+
+        let scale = Vector3 {
+            x: x.length(),
+            y: y.length(),
+            z: z.length(),
+        };
+        BoundingOrientedBox::new(
+            self.center.transform(m),
+            self.extents * scale,
+            self.orientation.quaternion_multiply(&rotation),
+        )
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        self.local_box().contains_point(&self.local_point(point))
+    }
+
+    pub fn contains_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> ContainmentType {
+        self.local_box().contains_triangle(&self.local_point(v0), &self.local_point(v1), &self.local_point(v2))
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        let local = BoundingSphere::new(self.local_point(&sphere.center), sphere.radius);
+        self.local_box().contains_sphere(&local)
+    }
+
+    pub fn contains_box(&self, b: &BoundingBox) -> ContainmentType {
+        self.contains_oriented_box(&BoundingOrientedBox::create_from_bounding_box(b))
+    }
+
+    pub fn contains_oriented_box(&self, b: &BoundingOrientedBox) -> ContainmentType {
+        let inside = |c: &Vector3| self.contains_point(c) == ContainmentType::Contains;
+        contains_corners(&b.get_corners(), inside, || self.intersects_oriented_box(b))
+    }
+
+    pub fn contains_frustum(&self, fr: &BoundingFrustum) -> ContainmentType {
+        let inside = |c: &Vector3| self.contains_point(c) == ContainmentType::Contains;
+        contains_corners(&fr.get_corners(), inside, || self.intersects_frustum(fr))
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        let local = BoundingSphere::new(self.local_point(&sphere.center), sphere.radius);
+        local.intersects_box(&self.local_box())
+    }
+
+    pub fn intersects_box(&self, b: &BoundingBox) -> bool {
+        self.intersects_oriented_box(&BoundingOrientedBox::create_from_bounding_box(b))
+    }
+
+    /// Separating axis test with the face axes of both boxes and the nine cross products of
+    /// their edges.
+    pub fn intersects_oriented_box(&self, b: &BoundingOrientedBox) -> bool {
+        let a_axes = rotated_axes(&self.orientation);
+        let b_axes = rotated_axes(&b.orientation);
+        let a_extents = self.extents;
+        let b_extents = b.extents;
+
+        // Rotation expressing b in the frame of a, and the offset of b in the frame of a
+        let mut r = [[0.0; 3]; 3];
+        let mut abs_r = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a_axes[i].dot(&b_axes[j]);
+                // The epsilon keeps the cross products of (nearly) parallel edges from
+                // reporting a separation
+                abs_r[i][j] = r[i][j].abs() + 1e-6;
+            }
+        }
+        let offset = b.center - self.center;
+        let t = [offset.dot(&a_axes[0]), offset.dot(&a_axes[1]), offset.dot(&a_axes[2])];
+
+        // Axes of a
+        for i in 0..3 {
+            let ra = a_extents[i];
+            let rb = b_extents[0] * abs_r[i][0] + b_extents[1] * abs_r[i][1] + b_extents[2] * abs_r[i][2];
+            if t[i].abs() > ra + rb {
+                return false;
+            }
+        }
+
+        // Axes of b
+        for j in 0..3 {
+            let ra = a_extents[0] * abs_r[0][j] + a_extents[1] * abs_r[1][j] + a_extents[2] * abs_r[2][j];
+            let rb = b_extents[j];
+            if (t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j]).abs() > ra + rb {
+                return false;
+            }
+        }
+
+        // Cross products of the axes of a and b
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = a_extents[i1] * abs_r[i2][j] + a_extents[i2] * abs_r[i1][j];
+                let rb = b_extents[j1] * abs_r[i][j2] + b_extents[j2] * abs_r[i][j1];
+                if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn intersects_frustum(&self, fr: &BoundingFrustum) -> bool {
+        fr.intersects_oriented_box(self)
+    }
+
+    pub fn intersects_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> bool {
+        self.local_box().intersects_triangle(&self.local_point(v0), &self.local_point(v1), &self.local_point(v2))
+    }
+
+    /// The plane (a, b, c, d) must be normalized.
+    pub fn intersects_plane(&self, plane: &Vector4) -> PlaneIntersectionType {
+        let dist = plane_dot_coord(plane, &self.center);
+
+        // Half the length of the projection of the box onto the plane normal
+        let normal = Vector3 {
+            x: plane.x,
+            y: plane.y,
+            z: plane.z,
+        };
+        let axes = rotated_axes(&self.orientation);
+        let radius = self.extents.x * normal.dot(&axes[0]).abs()
+            + self.extents.y * normal.dot(&axes[1]).abs()
+            + self.extents.z * normal.dot(&axes[2]).abs();
+
+        if dist > radius {
+            PlaneIntersectionType::Front
+        } else if dist < -radius {
+            PlaneIntersectionType::Back
+        } else {
+            PlaneIntersectionType::Intersecting
+        }
+    }
+
+    /// Distance along `direction` to where the ray enters the slabs of the box, negative when
+    /// the ray starts inside the box.
+    pub fn intersects_ray(&self, origin: &Vector3, direction: &Vector3) -> Option<f32> {
+        self.local_box().intersects_ray(&self.local_point(origin), &direction.inverse_rotate(&self.orientation))
+    }
+}
@@ -0,0 +1,251 @@
+use matrix::Matrix;
+use vector::{Vector, Vector3, Vector4};
+use super::{BoundingBox, BoundingFrustum, BoundingOrientedBox, ContainmentType, PlaneIntersectionType};
+use super::{contains_corners, plane_dot_coord, point_on_line_segment_nearest_point, point_on_plane_inside_triangle};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BoundingSphere {
+    pub center: Vector3,
+    pub radius: f32,
+}
+
+impl BoundingSphere {
+    pub fn new(center: Vector3, radius: f32) -> Self {
+        assert!(radius >= 0.0);
+        BoundingSphere {
+            center,
+            radius,
+        }
+    }
+
+    pub fn create_from_bounding_box(b: &BoundingBox) -> Self {
+        BoundingSphere::new(b.center, b.extents.length())
+    }
+
+    /// The smallest sphere enclosing both spheres.
+    pub fn create_merged(s1: &BoundingSphere, s2: &BoundingSphere) -> Self {
+        let v = s2.center - s1.center;
+        let dist = v.length();
+
+        if s1.radius + dist <= s2.radius {
+            return *s2;
+        }
+        if s2.radius + dist <= s1.radius {
+            return *s1;
+        }
+
+        let n = v * (1.0 / dist);
+        let t1 = (-s1.radius).min(dist - s2.radius);
+        let t2 = s1.radius.max(dist + s2.radius);
+        let t_5 = (t2 - t1) * 0.5;
+
+        BoundingSphere::new(s1.center + n * (t_5 + t1), t_5)
+    }
+
+    /// Approximate bounding sphere, starting from the two farthest apart of the axis-aligned
+    /// extreme points and growing it to enclose the rest (Ritter).
+    pub fn create_from_points(points: &[Vector3]) -> Self {
+        assert!(!points.is_empty());
+
+        let (mut min_x, mut max_x) = (points[0], points[0]);
+        let (mut min_y, mut max_y) = (points[0], points[0]);
+        let (mut min_z, mut max_z) = (points[0], points[0]);
+        for p in points.iter() {
+            if p.x < min_x.x { min_x = *p; }
+            if p.x > max_x.x { max_x = *p; }
+            if p.y < min_y.y { min_y = *p; }
+            if p.y > max_y.y { max_y = *p; }
+            if p.z < min_z.z { min_z = *p; }
+            if p.z > max_z.z { max_z = *p; }
+        }
+
+        // Use the min/max pair that are farthest apart to form the initial sphere
+        let dist_x = (max_x - min_x).length();
+        let dist_y = (max_y - min_y).length();
+        let dist_z = (max_z - min_z).length();
+        let (min, max, dist) = if dist_x > dist_y {
+            if dist_x > dist_z { (min_x, max_x, dist_x) } else { (min_z, max_z, dist_z) }
+        } else {
+            if dist_y > dist_z { (min_y, max_y, dist_y) } else { (min_z, max_z, dist_z) }
+        };
+
+        let mut center = (min + max) * 0.5;
+        let mut radius = dist * 0.5;
+
+        // Add any points not inside the sphere
+        for p in points.iter() {
+            let delta = *p - center;
+            let dist = delta.length();
+            if dist > radius {
+                radius = (radius + dist) * 0.5;
+                center += delta * (1.0 - radius / dist);
+            }
+        }
+
+        // Rounding in the steps above can leave earlier points just outside, so measure the
+        // radius again the way contains_point does
+        let radius_sq = points.iter().map(|p| (*p - center).length_sq()).fold(0.0, f32::max);
+        let mut radius = radius_sq.sqrt();
+        while radius * radius < radius_sq {
+            radius = radius.next_up();
+        }
+
+        BoundingSphere::new(center, radius)
+    }
+
+    /// The matrix may scale non-uniformly, the radius grows by the largest scale.
+    pub fn transform(&self, m: &Matrix) -> Self {
+        let row_length_sq = |r: usize| m[r][0] * m[r][0] + m[r][1] * m[r][1] + m[r][2] * m[r][2];
+        let scale_sq = row_length_sq(0).max(row_length_sq(1)).max(row_length_sq(2));
+
+        BoundingSphere::new(self.center.transform(m), self.radius * scale_sq.sqrt())
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        if (*point - self.center).length_sq() <= self.radius * self.radius {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Disjoint
+        }
+    }
+
+    pub fn contains_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> ContainmentType {
+        if !self.intersects_triangle(v0, v1, v2) {
+            return ContainmentType::Disjoint;
+        }
+
+        let inside = |v: &Vector3| self.contains_point(v) == ContainmentType::Contains;
+        if inside(v0) && inside(v1) && inside(v2) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Intersects
+        }
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        let dist = (self.center - sphere.center).length();
+
+        if self.radius + sphere.radius < dist {
+            ContainmentType::Disjoint
+        } else if self.radius - sphere.radius >= dist {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Intersects
+        }
+    }
+
+    pub fn contains_box(&self, b: &BoundingBox) -> ContainmentType {
+        if !self.intersects_box(b) {
+            return ContainmentType::Disjoint;
+        }
+
+        if b.get_corners().iter().all(|c| self.contains_point(c) == ContainmentType::Contains) {
+            ContainmentType::Contains
+        } else {
+            ContainmentType::Intersects
+        }
+    }
+
+    pub fn contains_oriented_box(&self, b: &BoundingOrientedBox) -> ContainmentType {
+        let inside = |c: &Vector3| self.contains_point(c) == ContainmentType::Contains;
+        contains_corners(&b.get_corners(), inside, || self.intersects_oriented_box(b))
+    }
+
+    pub fn contains_frustum(&self, fr: &BoundingFrustum) -> ContainmentType {
+        let inside = |c: &Vector3| self.contains_point(c) == ContainmentType::Contains;
+        contains_corners(&fr.get_corners(), inside, || self.intersects_frustum(fr))
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        let radius = self.radius + sphere.radius;
+        (self.center - sphere.center).length_sq() <= radius * radius
+    }
+
+    pub fn intersects_box(&self, b: &BoundingBox) -> bool {
+        // Squared distance from the center to the box (Arvo)
+        let min = b.center - b.extents;
+        let max = b.center + b.extents;
+        let mut d = 0.0;
+        for i in 0..3 {
+            let c = self.center[i];
+            if c < min[i] {
+                d += (c - min[i]) * (c - min[i]);
+            } else if c > max[i] {
+                d += (c - max[i]) * (c - max[i]);
+            }
+        }
+
+        d <= self.radius * self.radius
+    }
+
+    pub fn intersects_oriented_box(&self, b: &BoundingOrientedBox) -> bool {
+        b.intersects_sphere(self)
+    }
+
+    pub fn intersects_frustum(&self, fr: &BoundingFrustum) -> bool {
+        fr.intersects_sphere(self)
+    }
+
+    pub fn intersects_triangle(&self, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> bool {
+        let radius_sq = self.radius * self.radius;
+
+        // A degenerate triangle has no plane, only its edges can touch the sphere
+        let n = (*v1 - *v0).cross(&(*v2 - *v0));
+        if n.length_sq() > 0.0 {
+            let n = n.normalize();
+
+            // The center is farther from the plane of the triangle than the radius
+            let dist = (self.center - *v0).dot(&n);
+            if dist < -self.radius || dist > self.radius {
+                return false;
+            }
+
+            // The projection of the center lies inside every edge
+            let point = self.center - n * dist;
+            if point_on_plane_inside_triangle(&point, v0, v1, v2) {
+                return true;
+            }
+        }
+
+        // Find the nearest point on each edge
+        let near = |s1: &Vector3, s2: &Vector3| {
+            let point = point_on_line_segment_nearest_point(s1, s2, &self.center);
+            (self.center - point).length_sq() <= radius_sq
+        };
+        near(v0, v1) || near(v1, v2) || near(v2, v0)
+    }
+
+    /// The plane (a, b, c, d) must be normalized.
+    pub fn intersects_plane(&self, plane: &Vector4) -> PlaneIntersectionType {
+        let dist = plane_dot_coord(plane, &self.center);
+
+        if dist > self.radius {
+            PlaneIntersectionType::Front
+        } else if dist < -self.radius {
+            PlaneIntersectionType::Back
+        } else {
+            PlaneIntersectionType::Intersecting
+        }
+    }
+
+    /// Distance along the normalized `direction` to the first hit, if any. A ray starting
+    /// inside the sphere hits its far side.
+    pub fn intersects_ray(&self, origin: &Vector3, direction: &Vector3) -> Option<f32> {
+        let l = self.center - *origin;
+
+        // s is the projection of l onto the ray direction
+        let s = l.dot(direction);
+        let l2 = l.length_sq();
+        let r2 = self.radius * self.radius;
+
+        // m2 is squared distance from the center of the sphere to the projection
+        let m2 = l2 - s * s;
+
+        if (s < 0.0 && l2 > r2) || m2 > r2 {
+            return None;
+        }
+
+        let q = (r2 - m2).sqrt();
+        if l2 <= r2 { Some(s + q) } else { Some(s - q) }
+    }
+}
@@ -0,0 +1,84 @@
+use vector::{Vector3, Vector4};
+
+mod bounding_box;
+mod bounding_frustum;
+mod bounding_oriented_box;
+mod bounding_sphere;
+pub mod triangle_tests;
+
+pub use self::bounding_box::BoundingBox;
+pub use self::bounding_frustum::BoundingFrustum;
+pub use self::bounding_oriented_box::BoundingOrientedBox;
+pub use self::bounding_sphere::BoundingSphere;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ContainmentType {
+    Disjoint,
+    Intersects,
+    Contains,
+}
+
+/// Which side of a plane a volume lies on, relative to the plane normal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlaneIntersectionType {
+    Front,
+    Intersecting,
+    Back,
+}
+
+// Signed distance from a normalized plane (x, y, z, w) = (a, b, c, d)
+fn plane_dot_coord(plane: &Vector4, point: &Vector3) -> f32 {
+    plane.x * point.x + plane.y * point.y + plane.z * point.z + plane.w
+}
+
+// Containment of a convex volume, which is inside a convex volume exactly when its corners are
+fn contains_corners<P, I>(corners: &[Vector3], inside: P, intersects: I) -> ContainmentType
+    where P: Fn(&Vector3) -> bool, I: FnOnce() -> bool
+{
+    if corners.iter().all(inside) {
+        ContainmentType::Contains
+    } else if intersects() {
+        ContainmentType::Intersects
+    } else {
+        ContainmentType::Disjoint
+    }
+}
+
+// The local x, y and z axes after rotating by a unit quaternion
+fn rotated_axes(orientation: &Vector4) -> [Vector3; 3] {
+    [
+        Vector3 { x: 1.0, y: 0.0, z: 0.0 }.rotate(orientation),
+        Vector3 { x: 0.0, y: 1.0, z: 0.0 }.rotate(orientation),
+        Vector3 { x: 0.0, y: 0.0, z: 1.0 }.rotate(orientation),
+    ]
+}
+
+// The point must lie on the plane of the triangle
+fn point_on_plane_inside_triangle(p: &Vector3, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> bool {
+    let n = (*v2 - *v0).cross(&(*v1 - *v0));
+
+    // If the cross product points in the same direction as the normal then the point is inside
+    // the edge (it is zero if is on the edge)
+    let c0 = (*p - *v0).cross(&(*v1 - *v0));
+    let c1 = (*p - *v1).cross(&(*v2 - *v1));
+    let c2 = (*p - *v2).cross(&(*v0 - *v2));
+
+    c0.dot(&n) >= 0.0 && c1.dot(&n) >= 0.0 && c2.dot(&n) >= 0.0
+}
+
+fn point_on_line_segment_nearest_point(s1: &Vector3, s2: &Vector3, p: &Vector3) -> Vector3 {
+    let dir = *s2 - *s1;
+    let length_sq = dir.length_sq();
+    if length_sq == 0.0 {
+        return *s1;
+    }
+
+    let t = (p.dot(&dir) - s1.dot(&dir)) / length_sq;
+    if t < 0.0 {
+        *s1
+    } else if t > 1.0 {
+        *s2
+    } else {
+        *s1 + dir * t
+    }
+}
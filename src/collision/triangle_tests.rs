@@ -0,0 +1,220 @@
+use std::f32;
+use vector::{Vector, Vector3, Vector4};
+use super::{ContainmentType, PlaneIntersectionType};
+use super::{plane_dot_coord, point_on_plane_inside_triangle};
+
+fn project(axis: &Vector3, points: &[Vector3]) -> (f32, f32) {
+    let mut min = f32::MAX;
+    let mut max = -f32::MAX;
+    for p in points.iter() {
+        let d = p.dot(axis);
+        min = min.min(d);
+        max = max.max(d);
+    }
+    (min, max)
+}
+
+fn separated(axis: &Vector3, a: &[Vector3], b: &[Vector3]) -> bool {
+    let (a_min, a_max) = project(axis, a);
+    let (b_min, b_max) = project(axis, b);
+    a_min > b_max || b_min > a_max
+}
+
+// Separating axis test of a segment (or a point when s0 == s1) and a non-degenerate triangle
+fn segment_intersects_triangle(s0: &Vector3, s1: &Vector3, t: &[Vector3; 3], normal: &Vector3) -> bool {
+    let segment = [*s0, *s1];
+    let dir = *s1 - *s0;
+
+    if separated(normal, &segment, t) {
+        return false;
+    }
+    // Within the plane of the triangle, across the segment
+    if dir != Vector3::zero() && separated(&normal.cross(&dir), &segment, t) {
+        return false;
+    }
+    for i in 0..3 {
+        let edge = t[(i + 1) % 3] - t[i];
+        if separated(&dir.cross(&edge), &segment, t) || separated(&normal.cross(&edge), &segment, t) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn segments_intersect(p0: &Vector3, p1: &Vector3, q0: &Vector3, q1: &Vector3) -> bool {
+    // Nearest points of the two segments (Ericson)
+    let d1 = *p1 - *p0;
+    let d2 = *q1 - *q0;
+    let r = *p0 - *q0;
+    let a = d1.length_sq();
+    let e = d2.length_sq();
+    let f = d2.dot(&r);
+    let clamp = |x: f32| x.clamp(0.0, 1.0);
+
+    let (s, t) = if a == 0.0 && e == 0.0 {
+        (0.0, 0.0)
+    } else if a == 0.0 {
+        (0.0, clamp(f / e))
+    } else {
+        let c = d1.dot(&r);
+        if e == 0.0 {
+            (clamp(-c / a), 0.0)
+        } else {
+            let b = d1.dot(&d2);
+            let denom = a * e - b * b;
+            let s = if denom != 0.0 { clamp((b * f - c * e) / denom) } else { 0.0 };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                (clamp(-c / a), 0.0)
+            } else if t > 1.0 {
+                (clamp((b - c) / a), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    let dist_sq = (*p0 + d1 * s - (*q0 + d2 * t)).length_sq();
+    dist_sq <= 1e-12 * a.max(e).max(1.0)
+}
+
+/// Distance along `direction` to the nearest hit on the triangle, from either side. A ray lying
+/// in the plane of the triangle hits it where it enters the triangle, and degenerate triangles
+/// are never hit.
+pub fn intersects_ray(origin: &Vector3, direction: &Vector3, v0: &Vector3, v1: &Vector3, v2: &Vector3) -> Option<f32> {
+    let e1 = *v1 - *v0;
+    let e2 = *v2 - *v0;
+
+    // Moller-Trumbore
+    let p = direction.cross(&e2);
+    let det = e1.dot(&p);
+    if det.abs() > 1e-20 {
+        let inv_det = 1.0 / det;
+        let s = *origin - *v0;
+
+        let u = s.dot(&p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(&e1);
+        let v = direction.dot(&q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = e2.dot(&q) * inv_det;
+        return if t >= 0.0 { Some(t) } else { None };
+    }
+
+    // The ray is parallel to the plane of the triangle
+    let normal = e1.cross(&e2);
+    if normal == Vector3::zero() {
+        return None;
+    }
+    let normal = normal.normalize();
+    if normal.dot(&(*origin - *v0)).abs() > 1e-6 {
+        return None;
+    }
+    if point_on_plane_inside_triangle(origin, v0, v1, v2) {
+        return Some(0.0);
+    }
+
+    let vertices = [*v0, *v1, *v2];
+    let mut nearest: Option<f32> = None;
+    for i in 0..3 {
+        let a = vertices[i];
+        let edge = vertices[(i + 1) % 3] - a;
+        let denom = direction.cross(&edge).dot(&normal);
+        if denom == 0.0 {
+            continue;
+        }
+
+        let t = (a - *origin).cross(&edge).dot(&normal) / denom;
+        let s = (a - *origin).cross(direction).dot(&normal) / denom;
+        if t >= 0.0 && (0.0..=1.0).contains(&s) {
+            nearest = Some(nearest.map_or(t, |n| n.min(t)));
+        }
+    }
+    nearest
+}
+
+/// Separating axis test, which also covers coplanar triangles. A degenerate triangle is treated
+/// as the segment or point it collapses to.
+pub fn intersects_triangle(a0: &Vector3, a1: &Vector3, a2: &Vector3, b0: &Vector3, b1: &Vector3, b2: &Vector3) -> bool {
+    let a = [*a0, *a1, *a2];
+    let b = [*b0, *b1, *b2];
+    let a_normal = (*a1 - *a0).cross(&(*a2 - *a0));
+    let b_normal = (*b1 - *b0).cross(&(*b2 - *b0));
+    let a_degenerate = a_normal == Vector3::zero();
+    let b_degenerate = b_normal == Vector3::zero();
+
+    // Reduce a degenerate triangle to its edges
+    if a_degenerate && b_degenerate {
+        return (0..3).any(|i| (0..3).any(|j| segments_intersect(&a[i], &a[(i + 1) % 3], &b[j], &b[(j + 1) % 3])));
+    } else if a_degenerate {
+        return (0..3).any(|i| segment_intersects_triangle(&a[i], &a[(i + 1) % 3], &b, &b_normal));
+    } else if b_degenerate {
+        return (0..3).any(|i| segment_intersects_triangle(&b[i], &b[(i + 1) % 3], &a, &a_normal));
+    }
+
+    if separated(&a_normal, &a, &b) || separated(&b_normal, &a, &b) {
+        return false;
+    }
+
+    let a_edges = [a[1] - a[0], a[2] - a[1], a[0] - a[2]];
+    let b_edges = [b[1] - b[0], b[2] - b[1], b[0] - b[2]];
+    for ea in a_edges.iter() {
+        for eb in b_edges.iter() {
+            if separated(&ea.cross(eb), &a, &b) {
+                return false;
+            }
+        }
+    }
+
+    // Edge normals within the planes, which separate coplanar triangles
+    for e in a_edges.iter() {
+        if separated(&a_normal.cross(e), &a, &b) {
+            return false;
+        }
+    }
+    for e in b_edges.iter() {
+        if separated(&b_normal.cross(e), &a, &b) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// The plane (a, b, c, d) must be normalized.
+pub fn intersects_plane(v0: &Vector3, v1: &Vector3, v2: &Vector3, plane: &Vector4) -> PlaneIntersectionType {
+    let d0 = plane_dot_coord(plane, v0);
+    let d1 = plane_dot_coord(plane, v1);
+    let d2 = plane_dot_coord(plane, v2);
+
+    if d0 > 0.0 && d1 > 0.0 && d2 > 0.0 {
+        PlaneIntersectionType::Front
+    } else if d0 < 0.0 && d1 < 0.0 && d2 < 0.0 {
+        PlaneIntersectionType::Back
+    } else {
+        PlaneIntersectionType::Intersecting
+    }
+}
+
+/// Tests the triangle against a volume bounded by six planes facing outwards, such as
+/// `BoundingFrustum::get_planes`. As in DirectXCollision it may report `Intersects` for a
+/// triangle that is outside near an edge of the volume.
+pub fn contained_by(v0: &Vector3, v1: &Vector3, v2: &Vector3, planes: &[Vector4; 6]) -> ContainmentType {
+    let mut inside = true;
+    for plane in planes.iter() {
+        match intersects_plane(v0, v1, v2, plane) {
+            PlaneIntersectionType::Front => return ContainmentType::Disjoint,
+            PlaneIntersectionType::Intersecting => inside = false,
+            PlaneIntersectionType::Back => {}
+        }
+    }
+
+    if inside { ContainmentType::Contains } else { ContainmentType::Intersects }
+}
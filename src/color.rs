@@ -0,0 +1,368 @@
+use std::f32;
+use glium::uniforms::*;
+use packed;
+use packed::UByteN4;
+use vector::Vector4;
+
+// RGBA
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color(pub Vector4);
+
+// Row vector times a 3x3 matrix, alpha is passed through
+fn transform_rgb(v: &Vector4, m: &[[f32; 3]; 3]) -> Vector4 {
+    Vector4 {
+        x: v.x * m[0][0] + v.y * m[1][0] + v.z * m[2][0],
+        y: v.x * m[0][1] + v.y * m[1][1] + v.z * m[2][1],
+        z: v.x * m[0][2] + v.y * m[1][2] + v.z * m[2][2],
+        w: v.w,
+    }
+}
+
+fn map_rgb<F: Fn(f32) -> f32>(v: &Vector4, f: F) -> Vector4 {
+    Vector4 {
+        x: f(v.x),
+        y: f(v.y),
+        z: f(v.z),
+        w: v.w,
+    }
+}
+
+fn saturate(value: f32) -> f32 {
+    // NaN becomes 0
+    if value > 0.0 { value.min(1.0) } else { 0.0 }
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 { value / 12.92 } else { ((value + 0.055) / 1.055).powf(2.4) }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    if value < 0.0031308 { value * 12.92 } else { 1.055 * value.powf(1.0 / 2.4) - 0.055 }
+}
+
+// Hue in [0, 1), shared by HSL and HSV
+fn hue(v: &Vector4, max: f32, delta: f32) -> f32 {
+    let h = if v.x == max {
+        (v.y - v.z) / delta
+    } else if v.y == max {
+        (v.z - v.x) / delta + 2.0
+    } else {
+        (v.x - v.y) / delta + 4.0
+    } / 6.0;
+
+    if h < 0.0 { h + 1.0 } else { h }
+}
+
+// XMColorHue2Clr
+fn hue_to_color(p: f32, q: f32, h: f32) -> f32 {
+    let h = if h < 0.0 { h + 1.0 } else if h > 1.0 { h - 1.0 } else { h };
+
+    if h < 1.0 / 6.0 {
+        p + (q - p) * 6.0 * h
+    } else if h < 0.5 {
+        q
+    } else if h < 2.0 / 3.0 {
+        p + (q - p) * 6.0 * (2.0 / 3.0 - h)
+    } else {
+        p
+    }
+}
+
+const RGB_TO_YUV: [[f32; 3]; 3] = [
+    [0.299, -0.147, 0.615],
+    [0.587, -0.289, -0.515],
+    [0.114, 0.436, -0.100],
+];
+const YUV_TO_RGB: [[f32; 3]; 3] = [
+    [1.0, 1.0, 1.0],
+    [0.0, -0.395, 2.032],
+    [1.140, -0.581, 0.0],
+];
+
+const RGB_TO_YUV_HD: [[f32; 3]; 3] = [
+    [0.2126, -0.0997, 0.6150],
+    [0.7152, -0.3354, -0.5586],
+    [0.0722, 0.4351, -0.0564],
+];
+const YUV_HD_TO_RGB: [[f32; 3]; 3] = [
+    [1.0, 1.0, 1.0],
+    [0.0, -0.2153, 2.1324],
+    [1.2803, -0.3806, 0.0],
+];
+
+const RGB_TO_YUV_UHD: [[f32; 3]; 3] = [
+    [0.2627, -0.1215, 0.6150],
+    [0.6780, -0.3136, -0.5655],
+    [0.0593, 0.4351, -0.0495],
+];
+const YUV_UHD_TO_RGB: [[f32; 3]; 3] = [
+    [1.0, 1.0, 1.0],
+    [0.0, -0.1891, 2.1620],
+    [1.1989, -0.4645, 0.0],
+];
+
+// CIE RGB, normalized so that a luminance of 1 maps to Y = 1
+const RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.488718 / 0.17697, 0.1762044 / 0.17697, 0.0 / 0.17697],
+    [0.3106803 / 0.17697, 0.8129847 / 0.17697, 0.0102048 / 0.17697],
+    [0.2006017 / 0.17697, 0.0108109 / 0.17697, 0.9897952 / 0.17697],
+];
+const XYZ_TO_RGB: [[f32; 3]; 3] = [
+    [2.3706743 * 0.17697, -0.513885 * 0.17697, 0.0052982 * 0.17697],
+    [-0.9000405 * 0.17697, 1.4253036 * 0.17697, -0.0146949 * 0.17697],
+    [-0.4706338 * 0.17697, 0.0885814 * 0.17697, 1.0093968 * 0.17697],
+];
+
+// Linear sRGB with a D65 white point
+const SRGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.4124, 0.2126, 0.0193],
+    [0.3576, 0.7152, 0.1192],
+    [0.1805, 0.0722, 0.9505],
+];
+const XYZ_TO_SRGB: [[f32; 3]; 3] = [
+    [3.2406, -0.9689, 0.0557],
+    [-1.5372, 1.8758, -0.2040],
+    [-0.4986, 0.0415, 1.0570],
+];
+
+// Colour space conversions, with (x, y, z, w) holding (r, g, b, a) or the three components of
+// the other colour space followed by alpha
+impl Vector4 {
+    pub fn srgb_to_rgb(&self) -> Self {
+        map_rgb(self, |c| srgb_to_linear(saturate(c)))
+    }
+
+    pub fn rgb_to_srgb(&self) -> Self {
+        map_rgb(self, |c| linear_to_srgb(saturate(c)))
+    }
+
+    /// Hue, saturation and lightness, each in [0, 1].
+    pub fn rgb_to_hsl(&self) -> Self {
+        let min = self.x.min(self.y).min(self.z);
+        let max = self.x.max(self.y).max(self.z);
+        let l = (min + max) * 0.5;
+        let delta = max - min;
+
+        if delta < f32::EPSILON {
+            // Achromatic, assume H and S of 0
+            return Vector4 {
+                x: 0.0,
+                y: 0.0,
+                z: l,
+                w: self.w,
+            };
+        }
+
+        let s = if l > 0.5 { delta / (2.0 - max - min) } else { delta / (max + min) };
+        Vector4 {
+            x: hue(self, max, delta),
+            y: s,
+            z: l,
+            w: self.w,
+        }
+    }
+
+    pub fn hsl_to_rgb(&self) -> Self {
+        let (h, s, l) = (self.x, self.y, self.z);
+
+        if s.abs() < f32::EPSILON {
+            // Achromatic
+            return Vector4 {
+                x: l,
+                y: l,
+                z: l,
+                w: self.w,
+            };
+        }
+
+        let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+        let p = 2.0 * l - q;
+        Vector4 {
+            x: hue_to_color(p, q, h + 1.0 / 3.0),
+            y: hue_to_color(p, q, h),
+            z: hue_to_color(p, q, h - 1.0 / 3.0),
+            w: self.w,
+        }
+    }
+
+    /// Hue, saturation and value, each in [0, 1].
+    pub fn rgb_to_hsv(&self) -> Self {
+        let min = self.x.min(self.y).min(self.z);
+        let max = self.x.max(self.y).max(self.z);
+        let delta = max - min;
+        let s = if max.abs() < f32::EPSILON { 0.0 } else { delta / max };
+
+        Vector4 {
+            // Achromatic, assume H of 0
+            x: if delta < f32::EPSILON { 0.0 } else { hue(self, max, delta) },
+            y: s,
+            z: max,
+            w: self.w,
+        }
+    }
+
+    pub fn hsv_to_rgb(&self) -> Self {
+        let (h, s, v) = (self.x, self.y, self.z);
+        let h6 = h * 6.0;
+        let i = h6.floor();
+        let f = h6 - i;
+
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - f * s);
+        let t = v * (1.0 - (1.0 - f) * s);
+
+        let (r, g, b) = match (i % 6.0 + 6.0) % 6.0 {
+            i if i < 1.0 => (v, t, p),
+            i if i < 2.0 => (q, v, p),
+            i if i < 3.0 => (p, v, t),
+            i if i < 4.0 => (p, q, v),
+            i if i < 5.0 => (t, p, v),
+            _ => (v, p, q),
+        };
+        Vector4 {
+            x: r,
+            y: g,
+            z: b,
+            w: self.w,
+        }
+    }
+
+    /// BT.601
+    pub fn rgb_to_yuv(&self) -> Self {
+        transform_rgb(self, &RGB_TO_YUV)
+    }
+
+    pub fn yuv_to_rgb(&self) -> Self {
+        transform_rgb(self, &YUV_TO_RGB)
+    }
+
+    /// BT.709
+    pub fn rgb_to_yuv_hd(&self) -> Self {
+        transform_rgb(self, &RGB_TO_YUV_HD)
+    }
+
+    pub fn yuv_hd_to_rgb(&self) -> Self {
+        transform_rgb(self, &YUV_HD_TO_RGB)
+    }
+
+    /// BT.2020
+    pub fn rgb_to_yuv_uhd(&self) -> Self {
+        transform_rgb(self, &RGB_TO_YUV_UHD)
+    }
+
+    pub fn yuv_uhd_to_rgb(&self) -> Self {
+        transform_rgb(self, &YUV_UHD_TO_RGB)
+    }
+
+    pub fn rgb_to_xyz(&self) -> Self {
+        transform_rgb(self, &RGB_TO_XYZ)
+    }
+
+    pub fn xyz_to_rgb(&self) -> Self {
+        transform_rgb(self, &XYZ_TO_RGB)
+    }
+
+    pub fn srgb_to_xyz(&self) -> Self {
+        transform_rgb(&map_rgb(self, srgb_to_linear), &SRGB_TO_XYZ)
+    }
+
+    pub fn xyz_to_srgb(&self) -> Self {
+        map_rgb(&transform_rgb(self, &XYZ_TO_SRGB), linear_to_srgb)
+    }
+}
+
+impl Color {
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color(Vector4 {
+            x: r,
+            y: g,
+            z: b,
+            w: a,
+        })
+    }
+
+    pub fn r(&self) -> f32 { self.0.x }
+    pub fn g(&self) -> f32 { self.0.y }
+    pub fn b(&self) -> f32 { self.0.z }
+    pub fn a(&self) -> f32 { self.0.w }
+
+    /// 0 is greyscale, 1 leaves the colour unchanged.
+    pub fn adjust_saturation(&self, saturation: f32) -> Self {
+        let luminance = self.r() * 0.2125 + self.g() * 0.7154 + self.b() * 0.0721;
+        Color(map_rgb(&self.0, |c| (c - luminance) * saturation + luminance))
+    }
+
+    /// 0 is mid-grey, 1 leaves the colour unchanged.
+    pub fn adjust_contrast(&self, contrast: f32) -> Self {
+        Color(map_rgb(&self.0, |c| (c - 0.5) * contrast + 0.5))
+    }
+
+    pub fn negative(&self) -> Self {
+        Color(map_rgb(&self.0, |c| 1.0 - c))
+    }
+
+    pub fn modulate(&self, other: &Self) -> Self {
+        Color(self.0 * other.0)
+    }
+
+    pub fn premultiply_alpha(&self) -> Self {
+        let a = self.a();
+        Color(map_rgb(&self.0, |c| c * a))
+    }
+
+    /// Fully transparent colours become transparent black.
+    pub fn unpremultiply_alpha(&self) -> Self {
+        let a = self.a();
+        Color(map_rgb(&self.0, |c| if a == 0.0 { 0.0 } else { c / a }))
+    }
+
+    // Comparisons hold only if they hold for every component, like XMColorEqual
+    pub fn equal(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+    pub fn not_equal(&self, other: &Self) -> bool {
+        !self.equal(other)
+    }
+    pub fn greater(&self, other: &Self) -> bool {
+        self.r() > other.r() && self.g() > other.g() && self.b() > other.b() && self.a() > other.a()
+    }
+    pub fn greater_or_equal(&self, other: &Self) -> bool {
+        self.r() >= other.r() && self.g() >= other.g() && self.b() >= other.b() && self.a() >= other.a()
+    }
+    pub fn less(&self, other: &Self) -> bool {
+        self.r() < other.r() && self.g() < other.g() && self.b() < other.b() && self.a() < other.a()
+    }
+    pub fn less_or_equal(&self, other: &Self) -> bool {
+        self.r() <= other.r() && self.g() <= other.g() && self.b() <= other.b() && self.a() <= other.a()
+    }
+
+    // True if any component is NaN, like XMColorIsNaN
+    pub fn is_nan(&self) -> bool {
+        self.r().is_nan() || self.g().is_nan() || self.b().is_nan() || self.a().is_nan()
+    }
+    pub fn is_infinite(&self) -> bool {
+        self.r().is_infinite() || self.g().is_infinite() || self.b().is_infinite() || self.a().is_infinite()
+    }
+
+    pub fn from_bgra8(packed: &packed::Color) -> Self {
+        Color(packed.load())
+    }
+
+    pub fn to_bgra8(&self) -> packed::Color {
+        packed::Color::store(&self.0)
+    }
+
+    pub fn from_rgba8(packed: &UByteN4) -> Self {
+        Color(packed.load())
+    }
+
+    pub fn to_rgba8(&self) -> UByteN4 {
+        UByteN4::store(&self.0)
+    }
+}
+
+impl AsUniformValue for Color {
+    fn as_uniform_value(&self) -> UniformValue<'static> {
+        UniformValue::Vec4([self.0.x, self.0.y, self.0.z, self.0.w])
+    }
+}
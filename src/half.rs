@@ -0,0 +1,125 @@
+use vector::{Vector2, Vector4};
+
+pub type Half = u16;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Half2 {
+    pub x: Half,
+    pub y: Half,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Half4 {
+    pub x: Half,
+    pub y: Half,
+    pub z: Half,
+    pub w: Half,
+}
+
+// XMConvertHalfToFloat
+pub fn convert_half_to_float(value: Half) -> f32 {
+    let value = value as u32;
+    let mut mantissa = value & 0x03FF;
+    let exponent = if value & 0x7C00 == 0x7C00 {
+        // INF/NAN
+        0x8F
+    } else if value & 0x7C00 != 0 {
+        (value >> 10) & 0x1F
+    } else if mantissa != 0 {
+        // Normalize the denormalized value
+        let mut exponent = 1u32;
+        loop {
+            exponent = exponent.wrapping_sub(1);
+            mantissa <<= 1;
+            if mantissa & 0x0400 != 0 { break; }
+        }
+        mantissa &= 0x03FF;
+        exponent
+    } else {
+        // Zero
+        (-112i32) as u32
+    };
+
+    let result = ((value & 0x8000) << 16) | (exponent.wrapping_add(112) << 23) | (mantissa << 13);
+    f32::from_bits(result)
+}
+
+// XMConvertFloatToHalf
+pub fn convert_float_to_half(value: f32) -> Half {
+    let bits = value.to_bits();
+    let sign = (bits & 0x8000_0000) >> 16;
+    let bits = bits & 0x7FFF_FFFF;
+
+    let result = if bits >= 0x4780_0000 {
+        // Too large to be represented as a half, infinity or NaN
+        0x7C00 | if bits > 0x7F80_0000 { 0x200 | ((bits >> 13) & 0x3FF) } else { 0 }
+    } else if bits <= 0x3300_0000 {
+        // Rounds to zero
+        0
+    } else if bits < 0x3880_0000 {
+        // Too small to be represented as a normalized half, convert to a denormalized value
+        let shift = 125 - (bits >> 23);
+        let bits = 0x80_0000 | (bits & 0x7F_FFFF);
+        let result = bits >> (shift + 1);
+        let sticky = if bits & ((1 << shift) - 1) != 0 { 1 } else { 0 };
+        result + ((result | sticky) & ((bits >> shift) & 1))
+    } else {
+        // Rebias the exponent to represent the value as a normalized half
+        let bits = bits.wrapping_add(0xC800_0000);
+        ((bits + 0x0FFF + ((bits >> 13) & 1)) >> 13) & 0x7FFF
+    };
+
+    (result | sign) as Half
+}
+
+pub fn convert_half_to_float_stream(output: &mut [f32], input: &[Half]) {
+    assert_eq!(output.len(), input.len());
+    for (o, i) in output.iter_mut().zip(input.iter()) {
+        *o = convert_half_to_float(*i);
+    }
+}
+
+pub fn convert_float_to_half_stream(output: &mut [Half], input: &[f32]) {
+    assert_eq!(output.len(), input.len());
+    for (o, i) in output.iter_mut().zip(input.iter()) {
+        *o = convert_float_to_half(*i);
+    }
+}
+
+impl Half2 {
+    pub fn load(&self) -> Vector2 {
+        Vector2 {
+            x: convert_half_to_float(self.x),
+            y: convert_half_to_float(self.y),
+        }
+    }
+
+    pub fn store(v: &Vector2) -> Self {
+        Half2 {
+            x: convert_float_to_half(v.x),
+            y: convert_float_to_half(v.y),
+        }
+    }
+}
+
+impl Half4 {
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: convert_half_to_float(self.x),
+            y: convert_half_to_float(self.y),
+            z: convert_half_to_float(self.z),
+            w: convert_half_to_float(self.w),
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        Half4 {
+            x: convert_float_to_half(v.x),
+            y: convert_float_to_half(v.y),
+            z: convert_float_to_half(v.z),
+            w: convert_float_to_half(v.w),
+        }
+    }
+}
@@ -1,10 +1,54 @@
 extern crate glium;
 
+mod collision;
+mod color;
+mod half;
 mod matrix;
+mod normal_encoding;
+pub mod packed;
+mod quaternion;
+pub mod sh;
+mod storage;
+mod swizzle;
 mod vector;
+mod viewport;
 
+pub use collision::BoundingBox;
+pub use collision::BoundingFrustum;
+pub use collision::BoundingOrientedBox;
+pub use collision::BoundingSphere;
+pub use collision::ContainmentType;
+pub use collision::PlaneIntersectionType;
+pub use collision::triangle_tests;
+pub use color::Color;
+pub use half::Half;
+pub use half::Half2;
+pub use half::Half4;
+pub use half::convert_half_to_float;
+pub use half::convert_float_to_half;
+pub use half::convert_half_to_float_stream;
+pub use half::convert_float_to_half_stream;
+pub use matrix::Handedness;
 pub use matrix::Matrix;
+pub use storage::Float2;
+pub use storage::Float2A;
+pub use storage::Float3;
+pub use storage::Float3A;
+pub use storage::Float3x3;
+pub use storage::Float3x4;
+pub use storage::Float4;
+pub use storage::Float4A;
+pub use storage::Float4x3;
+pub use storage::Float4x4A;
+pub use storage::Int2;
+pub use storage::Int3;
+pub use storage::Int4;
+pub use storage::UInt2;
+pub use storage::UInt3;
+pub use storage::UInt4;
 pub use vector::Vector;
+pub use vector::LineIntersection;
 pub use vector::Vector2;
 pub use vector::Vector3;
 pub use vector::Vector4;
+pub use viewport::Viewport;
@@ -1,12 +1,22 @@
 extern crate glium;
 
+use std::iter::Product;
 use std::mem::zeroed;
 use std::ops::*;
 use glium::uniforms::*;
+use vector::Vector4;
 
 #[derive(PartialEq, Clone, Debug)]
 pub struct Matrix { m: [[f32; 4]; 4] }
 
+/// How a matrix maps the handedness of the coordinate system, see `Matrix::handedness`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Handedness {
+    Preserved,
+    Mirrored,
+    Singular,
+}
+
 impl Matrix {
     pub fn zero() -> Self { unsafe { zeroed() } }
 
@@ -149,6 +159,73 @@ impl Matrix {
         }
     }
 
+    // 2x2 minors of the upper two rows (s) and the lower two rows (c), shared by the Laplace
+    // expansions of the determinant and the inverse
+    fn minors(&self) -> ([f32; 6], [f32; 6]) {
+        let m = &self.m;
+        let s = [
+            m[0][0]*m[1][1] - m[1][0]*m[0][1],
+            m[0][0]*m[1][2] - m[1][0]*m[0][2],
+            m[0][0]*m[1][3] - m[1][0]*m[0][3],
+            m[0][1]*m[1][2] - m[1][1]*m[0][2],
+            m[0][1]*m[1][3] - m[1][1]*m[0][3],
+            m[0][2]*m[1][3] - m[1][2]*m[0][3],
+        ];
+        let c = [
+            m[2][0]*m[3][1] - m[3][0]*m[2][1],
+            m[2][0]*m[3][2] - m[3][0]*m[2][2],
+            m[2][0]*m[3][3] - m[3][0]*m[2][3],
+            m[2][1]*m[3][2] - m[3][1]*m[2][2],
+            m[2][1]*m[3][3] - m[3][1]*m[2][3],
+            m[2][2]*m[3][3] - m[3][2]*m[2][3],
+        ];
+        (s, c)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let (s, c) = self.minors();
+        determinant_from_minors(&s, &c)
+    }
+
+    /// The result is not finite if the matrix is singular.
+    pub fn inverse(&self) -> Self {
+        let m = &self.m;
+        let (s, c) = self.minors();
+        let [s0, s1, s2, s3, s4, s5] = s;
+        let [c0, c1, c2, c3, c4, c5] = c;
+
+        let r = 1.0/determinant_from_minors(&s, &c);
+
+        Matrix {
+            m: [
+                [
+                    ( m[1][1]*c5 - m[1][2]*c4 + m[1][3]*c3)*r,
+                    (-m[0][1]*c5 + m[0][2]*c4 - m[0][3]*c3)*r,
+                    ( m[3][1]*s5 - m[3][2]*s4 + m[3][3]*s3)*r,
+                    (-m[2][1]*s5 + m[2][2]*s4 - m[2][3]*s3)*r,
+                ],
+                [
+                    (-m[1][0]*c5 + m[1][2]*c2 - m[1][3]*c1)*r,
+                    ( m[0][0]*c5 - m[0][2]*c2 + m[0][3]*c1)*r,
+                    (-m[3][0]*s5 + m[3][2]*s2 - m[3][3]*s1)*r,
+                    ( m[2][0]*s5 - m[2][2]*s2 + m[2][3]*s1)*r,
+                ],
+                [
+                    ( m[1][0]*c4 - m[1][1]*c2 + m[1][3]*c0)*r,
+                    (-m[0][0]*c4 + m[0][1]*c2 - m[0][3]*c0)*r,
+                    ( m[3][0]*s4 - m[3][1]*s2 + m[3][3]*s0)*r,
+                    (-m[2][0]*s4 + m[2][1]*s2 - m[2][3]*s0)*r,
+                ],
+                [
+                    (-m[1][0]*c3 + m[1][1]*c1 - m[1][2]*c0)*r,
+                    ( m[0][0]*c3 - m[0][1]*c1 + m[0][2]*c0)*r,
+                    (-m[3][0]*s3 + m[3][1]*s1 - m[3][2]*s0)*r,
+                    ( m[2][0]*s3 - m[2][1]*s1 + m[2][2]*s0)*r,
+                ],
+            ]
+        }
+    }
+
     pub fn transpose(self) -> Self {
         Matrix {
             m: [
@@ -159,6 +236,195 @@ impl Matrix {
             ]
         }
     }
+
+    pub fn transpose_mut(&mut self) {
+        for r in 0..4 {
+            for c in (r + 1)..4 {
+                let t = self.m[r][c];
+                self.m[r][c] = self.m[c][r];
+                self.m[c][r] = t;
+            }
+        }
+    }
+
+    /// Same as `(a * b).transpose()`, without the intermediate matrix.
+    pub fn multiply_transpose(a: &Matrix, b: &Matrix) -> Self {
+        let mut result = Matrix::zero();
+        for r in 0..4 {
+            let (x, y, z, w) = (a.m[r][0], a.m[r][1], a.m[r][2], a.m[r][3]);
+            for c in 0..4 {
+                result.m[c][r] = (b.m[0][c]*x)+(b.m[1][c]*y)+(b.m[2][c]*z)+(b.m[3][c]*w);
+            }
+        }
+        result
+    }
+
+    /// The outer product, whose element (r, c) is `v1[r] * v2[c]`.
+    pub fn vector_tensor_product(v1: &Vector4, v2: &Vector4) -> Self {
+        let row = |s: f32| [v2.x * s, v2.y * s, v2.z * s, v2.w * s];
+        Matrix {
+            m: [row(v1.x), row(v1.y), row(v1.z), row(v1.w)]
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Matrix::identity()
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.m.iter().any(|r| r.iter().any(|v| v.is_nan()))
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.m.iter().any(|r| r.iter().any(|v| v.is_infinite()))
+    }
+
+    /// Whether every element differs from the one in `other` by at most `epsilon`.
+    pub fn near_equal(&self, other: &Matrix, epsilon: f32) -> bool {
+        self.m.iter().zip(other.m.iter()).all(|(a, b)| a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() <= epsilon))
+    }
+
+    /// Whether the last column is (0, 0, 0, 1), i.e. the matrix has no projective part.
+    pub fn is_affine(&self, epsilon: f32) -> bool {
+        self.m[0][3].abs() <= epsilon
+            && self.m[1][3].abs() <= epsilon
+            && self.m[2][3].abs() <= epsilon
+            && (self.m[3][3] - 1.0).abs() <= epsilon
+    }
+
+    /// Whether the upper 3x3 rows are unit length and perpendicular to each other.
+    pub fn is_orthonormal(&self, epsilon: f32) -> bool {
+        let m = &self.m;
+        let dot = |a: usize, b: usize| m[a][0]*m[b][0] + m[a][1]*m[b][1] + m[a][2]*m[b][2];
+        (0..3).all(|i| (0..3).all(|j| {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            (dot(i, j) - expected).abs() <= epsilon
+        }))
+    }
+
+    /// From the sign of the determinant of the upper 3x3, which is positive when the matrix
+    /// keeps the handedness of the coordinate system, negative when it mirrors it, and 0 when it
+    /// is singular.
+    pub fn handedness(&self) -> Handedness {
+        let m = &self.m;
+        let det = m[0][0]*(m[1][1]*m[2][2] - m[1][2]*m[2][1])
+            - m[0][1]*(m[1][0]*m[2][2] - m[1][2]*m[2][0])
+            + m[0][2]*(m[1][0]*m[2][1] - m[1][1]*m[2][0]);
+
+        if det > 0.0 {
+            Handedness::Preserved
+        } else if det < 0.0 {
+            Handedness::Mirrored
+        } else {
+            Handedness::Singular
+        }
+    }
+
+    pub fn row(&self, index: usize) -> Vector4 {
+        let r = &self.m[index];
+        Vector4 { x: r[0], y: r[1], z: r[2], w: r[3] }
+    }
+
+    pub fn set_row(&mut self, index: usize, v: &Vector4) {
+        self.m[index] = [v.x, v.y, v.z, v.w];
+    }
+
+    pub fn column(&self, index: usize) -> Vector4 {
+        let m = &self.m;
+        Vector4 { x: m[0][index], y: m[1][index], z: m[2][index], w: m[3][index] }
+    }
+
+    pub fn set_column(&mut self, index: usize, v: &Vector4) {
+        self.m[0][index] = v.x;
+        self.m[1][index] = v.y;
+        self.m[2][index] = v.z;
+        self.m[3][index] = v.w;
+    }
+
+    fn map<F: Fn(f32) -> f32>(&self, f: F) -> Matrix {
+        let mut result = self.clone();
+        for v in result.m.iter_mut().flat_map(|r| r.iter_mut()) {
+            *v = f(*v);
+        }
+        result
+    }
+
+    fn zip<F: Fn(f32, f32) -> f32>(&self, rhs: &Matrix, f: F) -> Matrix {
+        let mut result = self.clone();
+        for (r, rhs) in result.m.iter_mut().zip(rhs.m.iter()) {
+            for (v, rhs) in r.iter_mut().zip(rhs.iter()) {
+                *v = f(*v, *rhs);
+            }
+        }
+        result
+    }
+}
+
+fn determinant_from_minors(s: &[f32; 6], c: &[f32; 6]) -> f32 {
+    s[0]*c[5] - s[1]*c[4] + s[2]*c[3] + s[3]*c[2] - s[4]*c[1] + s[5]*c[0]
+}
+
+// Implements an operator on owned matrices in terms of its implementation on references
+macro_rules! forward_binop {
+    ($op:ident, $method:ident) => {
+        impl $op for Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: Matrix) -> Matrix { (&self).$method(&rhs) }
+        }
+
+        impl $op<Matrix> for &Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: Matrix) -> Matrix { self.$method(&rhs) }
+        }
+
+        impl $op<&Matrix> for Matrix {
+            type Output = Matrix;
+            fn $method(self, rhs: &Matrix) -> Matrix { (&self).$method(rhs) }
+        }
+    }
+}
+
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+    fn add(self, rhs: &Matrix) -> Matrix { self.zip(rhs, |a, b| a + b) }
+}
+
+impl Sub<&Matrix> for &Matrix {
+    type Output = Matrix;
+    fn sub(self, rhs: &Matrix) -> Matrix { self.zip(rhs, |a, b| a - b) }
+}
+
+impl Neg for Matrix {
+    type Output = Matrix;
+    fn neg(self) -> Matrix { -&self }
+}
+
+impl Neg for &Matrix {
+    type Output = Matrix;
+    fn neg(self) -> Matrix { self.map(|v| -v) }
+}
+
+impl Mul<f32> for Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: f32) -> Matrix { &self * rhs }
+}
+
+impl Mul<f32> for &Matrix {
+    type Output = Matrix;
+    fn mul(self, rhs: f32) -> Matrix { self.map(|v| v * rhs) }
+}
+
+impl Div<f32> for Matrix {
+    type Output = Matrix;
+    fn div(self, rhs: f32) -> Matrix { &self / rhs }
+}
+
+impl Div<f32> for &Matrix {
+    type Output = Matrix;
+    fn div(self, rhs: f32) -> Matrix { self.map(|v| v / rhs) }
 }
 
 impl Mul for Matrix {
@@ -166,20 +432,20 @@ impl Mul for Matrix {
     fn mul(self, rhs: Matrix) -> Matrix { &self * &rhs }
 }
 
-impl<'a> Mul<Matrix> for &'a Matrix {
+impl Mul<Matrix> for &Matrix {
     type Output = Matrix;
     fn mul(self, rhs: Matrix) -> Matrix { self * &rhs }
 }
 
-impl<'a> Mul<&'a Matrix> for Matrix {
+impl Mul<&Matrix> for Matrix {
     type Output = Matrix;
-    fn mul(self, rhs: &'a Matrix) -> Matrix { &self * rhs }
+    fn mul(self, rhs: &Matrix) -> Matrix { &self * rhs }
 }
 
-impl<'a, 'b> Mul<&'a Matrix> for &'b Matrix {
+impl Mul<&Matrix> for &Matrix {
     type Output = Matrix;
 
-    fn mul(self, rhs: &'a Matrix) -> Matrix {
+    fn mul(self, rhs: &Matrix) -> Matrix {
         macro_rules! row {
             ($col:expr) => ({
                 let x = self.m[$col][0];
@@ -199,6 +465,46 @@ impl<'a, 'b> Mul<&'a Matrix> for &'b Matrix {
     }
 }
 
+impl AddAssign for Matrix {
+    fn add_assign(&mut self, rhs: Matrix) { *self = &*self + &rhs; }
+}
+
+impl AddAssign<&Matrix> for Matrix {
+    fn add_assign(&mut self, rhs: &Matrix) { *self = &*self + rhs; }
+}
+
+impl SubAssign for Matrix {
+    fn sub_assign(&mut self, rhs: Matrix) { *self = &*self - &rhs; }
+}
+
+impl SubAssign<&Matrix> for Matrix {
+    fn sub_assign(&mut self, rhs: &Matrix) { *self = &*self - rhs; }
+}
+
+impl MulAssign for Matrix {
+    fn mul_assign(&mut self, rhs: Matrix) { *self = &*self * &rhs; }
+}
+
+impl MulAssign<&Matrix> for Matrix {
+    fn mul_assign(&mut self, rhs: &Matrix) { *self = &*self * rhs; }
+}
+
+impl MulAssign<f32> for Matrix {
+    fn mul_assign(&mut self, rhs: f32) { *self = &*self * rhs; }
+}
+
+impl DivAssign<f32> for Matrix {
+    fn div_assign(&mut self, rhs: f32) { *self = &*self / rhs; }
+}
+
+impl Product for Matrix {
+    fn product<I: Iterator<Item = Matrix>>(iter: I) -> Self { iter.fold(Matrix::identity(), Mul::mul) }
+}
+
+impl<'a> Product<&'a Matrix> for Matrix {
+    fn product<I: Iterator<Item = &'a Matrix>>(iter: I) -> Self { iter.fold(Matrix::identity(), Mul::mul) }
+}
+
 impl AsUniformValue for Matrix {
     fn as_uniform_value(&self) -> UniformValue<'static> {
         UniformValue::Mat4(self.m)
@@ -207,7 +513,13 @@ impl AsUniformValue for Matrix {
 
 impl Index<usize> for Matrix {
     type Output = [f32;4];
-    fn index<'a>(&'a self, index: usize) -> &'a Self::Output {
+    fn index(&self, index: usize) -> &Self::Output {
         &self.m[index]
     }
 }
+
+impl IndexMut<usize> for Matrix {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.m[index]
+    }
+}
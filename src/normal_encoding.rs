@@ -0,0 +1,75 @@
+use packed::{ByteN2, ShortN2};
+use vector::{Vector2, Vector3};
+
+fn sign_not_zero(value: f32) -> f32 {
+    if value >= 0.0 { 1.0 } else { -1.0 }
+}
+
+// Folds the lower hemisphere over the diagonals of the upper one
+fn octahedral_wrap(v: &Vector2) -> Vector2 {
+    Vector2 {
+        x: (1.0 - v.y.abs()) * sign_not_zero(v.x),
+        y: (1.0 - v.x.abs()) * sign_not_zero(v.y),
+    }
+}
+
+impl Vector3 {
+    /// Maps a normalized vector onto the [-1, 1] square.
+    pub fn encode_octahedral(&self) -> Vector2 {
+        let l1 = self.x.abs() + self.y.abs() + self.z.abs();
+        let projected = Vector2 {
+            x: self.x / l1,
+            y: self.y / l1,
+        };
+        if self.z >= 0.0 { projected } else { octahedral_wrap(&projected) }
+    }
+
+    pub fn decode_octahedral(encoded: &Vector2) -> Self {
+        let z = 1.0 - encoded.x.abs() - encoded.y.abs();
+        let xy = if z >= 0.0 { *encoded } else { octahedral_wrap(encoded) };
+        Vector3 {
+            x: xy.x,
+            y: xy.y,
+            z,
+        }.normalize()
+    }
+
+    pub fn encode_octahedral_snorm16(&self) -> ShortN2 {
+        ShortN2::store(&self.encode_octahedral())
+    }
+
+    pub fn decode_octahedral_snorm16(encoded: &ShortN2) -> Self {
+        Vector3::decode_octahedral(&encoded.load())
+    }
+
+    pub fn encode_octahedral_snorm8(&self) -> ByteN2 {
+        ByteN2::store(&self.encode_octahedral())
+    }
+
+    pub fn decode_octahedral_snorm8(encoded: &ByteN2) -> Self {
+        Vector3::decode_octahedral(&encoded.load())
+    }
+
+    /// Maps a normalized vector onto the [0, 1] square. (0, 0, -1) cannot be encoded.
+    pub fn encode_spheremap(&self) -> Vector2 {
+        let f = (8.0 * self.z + 8.0).sqrt();
+        Vector2 {
+            x: self.x / f + 0.5,
+            y: self.y / f + 0.5,
+        }
+    }
+
+    pub fn decode_spheremap(encoded: &Vector2) -> Self {
+        let fenc = Vector2 {
+            x: encoded.x * 4.0 - 2.0,
+            y: encoded.y * 4.0 - 2.0,
+        };
+        let f = fenc.length_sq();
+        let g = (1.0 - f / 4.0).max(0.0).sqrt();
+        Vector3 {
+            x: fenc.x * g,
+            y: fenc.y * g,
+            z: 1.0 - f / 2.0,
+        }
+    }
+}
@@ -0,0 +1,492 @@
+use vector::{Vector, Vector2, Vector3, Vector4};
+
+/// Unsigned normalized 8-bit components.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UByteN4 {
+    pub x: u8,
+    pub y: u8,
+    pub z: u8,
+    pub w: u8,
+}
+
+/// Signed normalized 8-bit components.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ByteN4 {
+    pub x: i8,
+    pub y: i8,
+    pub z: i8,
+    pub w: i8,
+}
+
+/// Signed normalized 8-bit components.
+///
+/// Loaded into a `Vector2` rather than a `Vector4`, as it has no z and w; XMLoadByteN2 fills
+/// them with 0.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ByteN2 {
+    pub x: i8,
+    pub y: i8,
+}
+
+/// Signed normalized 16-bit components.
+///
+/// Loaded into a `Vector2` rather than a `Vector4`, as it has no z and w; XMLoadShortN2 fills
+/// them with 0.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ShortN2 {
+    pub x: i16,
+    pub y: i16,
+}
+
+/// Signed normalized 16-bit components.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ShortN4 {
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub w: i16,
+}
+
+/// Unsigned normalized 16-bit components.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UShortN4 {
+    pub x: u16,
+    pub y: u16,
+    pub z: u16,
+    pub w: u16,
+}
+
+/// Unsigned normalized A8R8G8B8, blue in the lowest byte.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Color {
+    pub c: u32,
+}
+
+/// Unsigned 5-6-5 integers, x in the lowest bits.
+///
+/// Loaded into a `Vector3` rather than a `Vector4`, as it has no w; XMLoadU565 fills it with 0.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct U565 {
+    pub v: u16,
+}
+
+/// Unsigned 5-5-5-1 integers, x in the lowest bits.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct U555 {
+    pub v: u16,
+}
+
+/// Unsigned 4-bit integers, x in the lowest bits.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UNibble4 {
+    pub v: u16,
+}
+
+/// Signed normalized 10-10-10 and unsigned normalized 2-bit w, x in the lowest bits.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct XDecN4 {
+    pub v: u32,
+}
+
+/// Unsigned normalized 10-10-10-2, x in the lowest bits.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UDecN4 {
+    pub v: u32,
+}
+
+/// Unsigned 11-11-10 floats with 6-6-5 bit mantissas, x in the lowest bits.
+///
+/// Loaded into a `Vector3` rather than a `Vector4`, as it has no w; XMLoadFloat3PK fills it
+/// with 0.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Float3PK {
+    pub v: u32,
+}
+
+/// Unsigned 9-9-9 mantissas sharing a 5-bit exponent, x in the lowest bits.
+///
+/// Loaded into a `Vector3` rather than a `Vector4`, as it has no w; XMLoadFloat3SE fills it
+/// with 0.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Float3SE {
+    pub v: u32,
+}
+
+fn saturate(value: f32) -> f32 {
+    // NaN becomes 0
+    if value > 0.0 { value.min(1.0) } else { 0.0 }
+}
+
+fn clamp_snorm(value: f32) -> f32 {
+    if value > -1.0 { value.min(1.0) } else if value <= -1.0 { -1.0 } else { 0.0 }
+}
+
+fn clamp_uint(value: f32, max: f32) -> u32 {
+    (if value > 0.0 { value.min(max) } else { 0.0 }).round_ties_even() as u32
+}
+
+impl UByteN4 {
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: self.x as f32 / 255.0,
+            y: self.y as f32 / 255.0,
+            z: self.z as f32 / 255.0,
+            w: self.w as f32 / 255.0,
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        UByteN4 {
+            x: (saturate(v.x) * 255.0).round_ties_even() as u8,
+            y: (saturate(v.y) * 255.0).round_ties_even() as u8,
+            z: (saturate(v.z) * 255.0).round_ties_even() as u8,
+            w: (saturate(v.w) * 255.0).round_ties_even() as u8,
+        }
+    }
+}
+
+impl ByteN4 {
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: (self.x as f32 / 127.0).max(-1.0),
+            y: (self.y as f32 / 127.0).max(-1.0),
+            z: (self.z as f32 / 127.0).max(-1.0),
+            w: (self.w as f32 / 127.0).max(-1.0),
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        ByteN4 {
+            x: (clamp_snorm(v.x) * 127.0).round_ties_even() as i8,
+            y: (clamp_snorm(v.y) * 127.0).round_ties_even() as i8,
+            z: (clamp_snorm(v.z) * 127.0).round_ties_even() as i8,
+            w: (clamp_snorm(v.w) * 127.0).round_ties_even() as i8,
+        }
+    }
+}
+
+impl ByteN2 {
+    pub fn load(&self) -> Vector2 {
+        Vector2 {
+            x: (self.x as f32 / 127.0).max(-1.0),
+            y: (self.y as f32 / 127.0).max(-1.0),
+        }
+    }
+
+    pub fn store(v: &Vector2) -> Self {
+        ByteN2 {
+            x: (clamp_snorm(v.x) * 127.0).round_ties_even() as i8,
+            y: (clamp_snorm(v.y) * 127.0).round_ties_even() as i8,
+        }
+    }
+}
+
+impl ShortN2 {
+    pub fn load(&self) -> Vector2 {
+        Vector2 {
+            x: (self.x as f32 / 32767.0).max(-1.0),
+            y: (self.y as f32 / 32767.0).max(-1.0),
+        }
+    }
+
+    pub fn store(v: &Vector2) -> Self {
+        ShortN2 {
+            x: (clamp_snorm(v.x) * 32767.0).round_ties_even() as i16,
+            y: (clamp_snorm(v.y) * 32767.0).round_ties_even() as i16,
+        }
+    }
+}
+
+impl ShortN4 {
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: (self.x as f32 / 32767.0).max(-1.0),
+            y: (self.y as f32 / 32767.0).max(-1.0),
+            z: (self.z as f32 / 32767.0).max(-1.0),
+            w: (self.w as f32 / 32767.0).max(-1.0),
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        ShortN4 {
+            x: (clamp_snorm(v.x) * 32767.0).round_ties_even() as i16,
+            y: (clamp_snorm(v.y) * 32767.0).round_ties_even() as i16,
+            z: (clamp_snorm(v.z) * 32767.0).round_ties_even() as i16,
+            w: (clamp_snorm(v.w) * 32767.0).round_ties_even() as i16,
+        }
+    }
+}
+
+impl UShortN4 {
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: self.x as f32 / 65535.0,
+            y: self.y as f32 / 65535.0,
+            z: self.z as f32 / 65535.0,
+            w: self.w as f32 / 65535.0,
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        UShortN4 {
+            x: (saturate(v.x) * 65535.0).round_ties_even() as u16,
+            y: (saturate(v.y) * 65535.0).round_ties_even() as u16,
+            z: (saturate(v.z) * 65535.0).round_ties_even() as u16,
+            w: (saturate(v.w) * 65535.0).round_ties_even() as u16,
+        }
+    }
+}
+
+impl Color {
+    /// Returns (r, g, b, a).
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: ((self.c >> 16) & 0xFF) as f32 / 255.0,
+            y: ((self.c >> 8) & 0xFF) as f32 / 255.0,
+            z: (self.c & 0xFF) as f32 / 255.0,
+            w: (self.c >> 24) as f32 / 255.0,
+        }
+    }
+
+    /// Takes (r, g, b, a).
+    pub fn store(v: &Vector4) -> Self {
+        let r = (saturate(v.x) * 255.0).round_ties_even() as u32;
+        let g = (saturate(v.y) * 255.0).round_ties_even() as u32;
+        let b = (saturate(v.z) * 255.0).round_ties_even() as u32;
+        let a = (saturate(v.w) * 255.0).round_ties_even() as u32;
+        Color {
+            c: (a << 24) | (r << 16) | (g << 8) | b,
+        }
+    }
+}
+
+impl U565 {
+    pub fn load(&self) -> Vector3 {
+        let v = self.v as u32;
+        Vector3 {
+            x: (v & 0x1F) as f32,
+            y: ((v >> 5) & 0x3F) as f32,
+            z: ((v >> 11) & 0x1F) as f32,
+        }
+    }
+
+    pub fn store(v: &Vector3) -> Self {
+        let x = clamp_uint(v.x, 31.0);
+        let y = clamp_uint(v.y, 63.0);
+        let z = clamp_uint(v.z, 31.0);
+        U565 {
+            v: (x | (y << 5) | (z << 11)) as u16,
+        }
+    }
+}
+
+impl U555 {
+    pub fn load(&self) -> Vector4 {
+        let v = self.v as u32;
+        Vector4 {
+            x: (v & 0x1F) as f32,
+            y: ((v >> 5) & 0x1F) as f32,
+            z: ((v >> 10) & 0x1F) as f32,
+            w: (v >> 15) as f32,
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        let x = clamp_uint(v.x, 31.0);
+        let y = clamp_uint(v.y, 31.0);
+        let z = clamp_uint(v.z, 31.0);
+        let w = clamp_uint(v.w, 1.0);
+        U555 {
+            v: (x | (y << 5) | (z << 10) | (w << 15)) as u16,
+        }
+    }
+}
+
+impl UNibble4 {
+    pub fn load(&self) -> Vector4 {
+        let v = self.v as u32;
+        Vector4 {
+            x: (v & 0xF) as f32,
+            y: ((v >> 4) & 0xF) as f32,
+            z: ((v >> 8) & 0xF) as f32,
+            w: (v >> 12) as f32,
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        let x = clamp_uint(v.x, 15.0);
+        let y = clamp_uint(v.y, 15.0);
+        let z = clamp_uint(v.z, 15.0);
+        let w = clamp_uint(v.w, 15.0);
+        UNibble4 {
+            v: (x | (y << 4) | (z << 8) | (w << 12)) as u16,
+        }
+    }
+}
+
+impl XDecN4 {
+    pub fn load(&self) -> Vector4 {
+        // Sign extend the 10-bit fields
+        let element = |shift: u32| (((self.v >> shift) << 22) as i32 >> 22) as f32;
+        Vector4 {
+            x: (element(0) / 511.0).max(-1.0),
+            y: (element(10) / 511.0).max(-1.0),
+            z: (element(20) / 511.0).max(-1.0),
+            w: (self.v >> 30) as f32 / 3.0,
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        let element = |value: f32| ((clamp_snorm(value) * 511.0).round_ties_even() as i32 as u32) & 0x3FF;
+        let w = (saturate(v.w) * 3.0).round_ties_even() as u32;
+        XDecN4 {
+            v: element(v.x) | (element(v.y) << 10) | (element(v.z) << 20) | (w << 30),
+        }
+    }
+}
+
+impl UDecN4 {
+    pub fn load(&self) -> Vector4 {
+        Vector4 {
+            x: (self.v & 0x3FF) as f32 / 1023.0,
+            y: ((self.v >> 10) & 0x3FF) as f32 / 1023.0,
+            z: ((self.v >> 20) & 0x3FF) as f32 / 1023.0,
+            w: (self.v >> 30) as f32 / 3.0,
+        }
+    }
+
+    pub fn store(v: &Vector4) -> Self {
+        let element = |value: f32| (saturate(value) * 1023.0).round_ties_even() as u32;
+        let w = (saturate(v.w) * 3.0).round_ties_even() as u32;
+        UDecN4 {
+            v: element(v.x) | (element(v.y) << 10) | (element(v.z) << 20) | (w << 30),
+        }
+    }
+}
+
+// Unsigned float with a 5-bit exponent, as in XMLoadFloat3PK
+fn small_float_to_float(value: u32, mantissa_bits: u32) -> f32 {
+    let mut mantissa = value & ((1 << mantissa_bits) - 1);
+    let exponent = (value >> mantissa_bits) & 0x1F;
+
+    let result = if exponent == 0x1F {
+        // INF/NAN
+        0x7F80_0000 | (mantissa << (23 - mantissa_bits))
+    } else {
+        let exponent = if exponent != 0 {
+            exponent
+        } else if mantissa != 0 {
+            // Normalize the denormalized value
+            let mut exponent = 1u32;
+            loop {
+                exponent = exponent.wrapping_sub(1);
+                mantissa <<= 1;
+                if mantissa & (1 << mantissa_bits) != 0 { break; }
+            }
+            mantissa &= (1 << mantissa_bits) - 1;
+            exponent
+        } else {
+            (-112i32) as u32
+        };
+        (exponent.wrapping_add(112) << 23) | (mantissa << (23 - mantissa_bits))
+    };
+    f32::from_bits(result)
+}
+
+// Unsigned float with a 5-bit exponent, as in XMStoreFloat3PK
+fn float_to_small_float(value: f32, mantissa_bits: u32) -> u32 {
+    let shift = 23 - mantissa_bits;
+    let mantissa_mask = (1 << mantissa_bits) - 1;
+    let bits = value.to_bits();
+    let sign = bits & 0x8000_0000;
+    let bits = bits & 0x7FFF_FFFF;
+
+    if bits & 0x7F80_0000 == 0x7F80_0000 {
+        if bits & 0x7F_FFFF != 0 {
+            // NAN
+            (0x1F << mantissa_bits) | mantissa_mask
+        } else if sign != 0 {
+            // -INF is clamped to 0 since the format is positive only
+            0
+        } else {
+            0x1F << mantissa_bits
+        }
+    } else if sign != 0 || bits < (113 - mantissa_bits) << 23 {
+        // Negative or smaller than the smallest denormal
+        0
+    } else if bits > (0x8E << 23) | (mantissa_mask << shift) {
+        // Too large, set to max
+        (0x1E << mantissa_bits) | mantissa_mask
+    } else {
+        let bits = if bits < 0x3880_0000 {
+            // Too small to be represented as a normalized value, convert to a denormalized value
+            let denormal_shift = 113 - (bits >> 23);
+            (0x80_0000 | (bits & 0x7F_FFFF)) >> denormal_shift
+        } else {
+            // Rebias the exponent to represent the value as a normalized value
+            bits.wrapping_add(0xC800_0000)
+        };
+        ((bits + (1 << (shift - 1)) - 1 + ((bits >> shift) & 1)) >> shift) & ((1 << (mantissa_bits + 5)) - 1)
+    }
+}
+
+impl Float3PK {
+    pub fn load(&self) -> Vector3 {
+        Vector3 {
+            x: small_float_to_float(self.v & 0x7FF, 6),
+            y: small_float_to_float((self.v >> 11) & 0x7FF, 6),
+            z: small_float_to_float(self.v >> 22, 5),
+        }
+    }
+
+    pub fn store(v: &Vector3) -> Self {
+        let x = float_to_small_float(v.x, 6);
+        let y = float_to_small_float(v.y, 6);
+        let z = float_to_small_float(v.z, 5);
+        Float3PK {
+            v: x | (y << 11) | (z << 22),
+        }
+    }
+}
+
+impl Float3SE {
+    pub fn load(&self) -> Vector3 {
+        let scale = f32::from_bits(0x3380_0000 + ((self.v >> 27) << 23));
+        Vector3 {
+            x: (self.v & 0x1FF) as f32 * scale,
+            y: ((self.v >> 9) & 0x1FF) as f32 * scale,
+            z: ((self.v >> 18) & 0x1FF) as f32 * scale,
+        }
+    }
+
+    pub fn store(v: &Vector3) -> Self {
+        const MAX_F9: f32 = (0x1FF << 7) as f32;
+        const MIN_F9: f32 = 1.0 / (1 << 16) as f32;
+
+        let v = v.clamp(&Vector3::zero(), &Vector3::replicate(MAX_F9));
+        let max_color = v.x.max(v.y).max(v.z).max(MIN_F9);
+
+        // Round up leaving 9 bits in fraction (including assumed 1)
+        let exponent = (max_color.to_bits() + 0x4000) >> 23;
+        let scale = f32::from_bits(0x8300_0000u32.wrapping_sub(exponent << 23));
+
+        let element = |value: f32| (value * scale).round_ties_even() as u32;
+        Float3SE {
+            v: element(v.x) | (element(v.y) << 9) | (element(v.z) << 18) | ((exponent - 0x6F) << 27),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use matrix::Matrix;
+use vector::{Vector3, Vector4};
+
+// Quaternions are stored in a Vector4 as (x, y, z, w) with w as the scalar part
+
+impl Vector4 {
+    pub fn quaternion_identity() -> Self {
+        Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }
+    }
+
+    /// Rotation of `angle` radians around `axis`, which does not have to be normalized.
+    pub fn quaternion_rotation_axis(axis: &Vector3, angle: f32) -> Self {
+        let (sin, cos) = (0.5 * angle).sin_cos();
+        let axis = axis.normalize() * sin;
+        Vector4 { x: axis.x, y: axis.y, z: axis.z, w: cos }
+    }
+
+    /// The rotation part of `m`, whose upper 3x3 has to be orthonormal.
+    pub fn quaternion_rotation_matrix(m: &Matrix) -> Self {
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        if trace > 0.0 {
+            let s = 0.5 / (trace + 1.0).sqrt();
+            Vector4 {
+                x: (m[1][2] - m[2][1]) * s,
+                y: (m[2][0] - m[0][2]) * s,
+                z: (m[0][1] - m[1][0]) * s,
+                w: 0.25 / s,
+            }
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let s = 0.5 / (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt();
+            Vector4 {
+                x: 0.25 / s,
+                y: (m[0][1] + m[1][0]) * s,
+                z: (m[0][2] + m[2][0]) * s,
+                w: (m[1][2] - m[2][1]) * s,
+            }
+        } else if m[1][1] >= m[2][2] {
+            let s = 0.5 / (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt();
+            Vector4 {
+                x: (m[0][1] + m[1][0]) * s,
+                y: 0.25 / s,
+                z: (m[1][2] + m[2][1]) * s,
+                w: (m[2][0] - m[0][2]) * s,
+            }
+        } else {
+            let s = 0.5 / (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt();
+            Vector4 {
+                x: (m[0][2] + m[2][0]) * s,
+                y: (m[1][2] + m[2][1]) * s,
+                z: 0.25 / s,
+                w: (m[0][1] - m[1][0]) * s,
+            }
+        }
+    }
+
+    /// The rotation `self` followed by the rotation `other`.
+    pub fn quaternion_multiply(&self, other: &Vector4) -> Self {
+        let (q1, q2) = (other, self);
+        Vector4 {
+            x: q1.w * q2.x + q1.x * q2.w + q1.y * q2.z - q1.z * q2.y,
+            y: q1.w * q2.y - q1.x * q2.z + q1.y * q2.w + q1.z * q2.x,
+            z: q1.w * q2.z + q1.x * q2.y - q1.y * q2.x + q1.z * q2.w,
+            w: q1.w * q2.w - q1.x * q2.x - q1.y * q2.y - q1.z * q2.z,
+        }
+    }
+
+    pub fn quaternion_conjugate(&self) -> Self {
+        Vector4 { x: -self.x, y: -self.y, z: -self.z, w: self.w }
+    }
+}
+
+impl Vector3 {
+    /// Rotates by a unit quaternion.
+    pub fn rotate(&self, q: &Vector4) -> Self {
+        let u = Vector3 { x: q.x, y: q.y, z: q.z };
+        let t = u.cross(self) * 2.0;
+        *self + t * q.w + u.cross(&t)
+    }
+
+    /// Rotates by the inverse of a unit quaternion.
+    pub fn inverse_rotate(&self, q: &Vector4) -> Self {
+        self.rotate(&q.quaternion_conjugate())
+    }
+}
+
+impl Matrix {
+    pub fn rotation_quaternion(q: &Vector4) -> Self {
+        let (x, y, z, w) = (q.x, q.y, q.z, q.w);
+        Matrix::new(
+            1.0 - 2.0*(y*y + z*z), 2.0*(x*y + z*w), 2.0*(x*z - y*w), 0.0,
+            2.0*(x*y - z*w), 1.0 - 2.0*(x*x + z*z), 2.0*(y*z + x*w), 0.0,
+            2.0*(x*z + y*w), 2.0*(y*z - x*w), 1.0 - 2.0*(x*x + y*y), 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+}
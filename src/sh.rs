@@ -0,0 +1,373 @@
+// Real spherical harmonics as in DirectXSH: the coefficient of band l and index m (-l <= m <= l)
+// is stored at l * (l + 1) + m, and the basis includes the Condon-Shortley phase. A set of
+// coefficients of order n holds the bands below n, that is n * n values.
+
+use std::f64::consts::{PI, SQRT_2};
+use matrix::Matrix;
+use vector::{Vector3, Vector4};
+
+pub const MIN_ORDER: usize = 2;
+pub const MAX_ORDER: usize = 6;
+
+const MAX_COEFFICIENTS: usize = MAX_ORDER * MAX_ORDER;
+
+// Gauss-Legendre nodes and weights; together with uniform samples in phi they integrate the
+// product of three functions of order MAX_ORDER exactly
+const QUADRATURE_NODES: [f64; 4] = [0.1834346424956498, 0.525532409916329, 0.7966664774136267, 0.9602898564975363];
+const QUADRATURE_WEIGHTS: [f64; 4] = [0.362683783378362, 0.3137066458778873, 0.2223810344533745, 0.1012285362903763];
+const QUADRATURE_PHI_SAMPLES: usize = 16;
+
+// Below this the caps of the cone and spherical lights vanish in single precision, leaving no
+// light to normalize
+const MIN_LIGHT_RADIUS: f32 = 1e-5;
+
+// Convolution of each band with the clamped cosine lobe, divided by pi
+const COSINE_LOBE: [f32; MAX_ORDER] = [1.0, 2.0 / 3.0, 1.0 / 4.0, 0.0, -1.0 / 24.0, 0.0];
+
+fn check(order: usize, coefficients: &[f32]) {
+    assert!((MIN_ORDER..=MAX_ORDER).contains(&order));
+    assert!(coefficients.len() >= order * order);
+}
+
+fn eval_basis(result: &mut [f32], order: usize, dir: &Vector3) {
+    let (x, y, z) = (dir.x as f64, dir.y as f64, dir.z as f64);
+
+    // sin^m(theta) cos(m phi) and sin^m(theta) sin(m phi)
+    let (mut c, mut s) = (1.0, 0.0);
+    // (-1)^m (2m - 1)!!
+    let mut p_mm = 1.0;
+
+    for m in 0..order {
+        if m > 0 {
+            p_mm *= -(2.0 * m as f64 - 1.0);
+        }
+
+        // The associated Legendre polynomial P(l, m) divided by sin^m(theta), by recurrence on l
+        let (mut q1, mut q2) = (0.0, 0.0);
+        for l in m..order {
+            let q = if l == m {
+                p_mm
+            } else {
+                ((2 * l - 1) as f64 * z * q1 - (l + m - 1) as f64 * q2) / (l - m) as f64
+            };
+            q2 = q1;
+            q1 = q;
+
+            // sqrt((2l + 1) / 4pi * (l - m)! / (l + m)!)
+            let mut k = (2 * l + 1) as f64 / (4.0 * PI);
+            for i in (l - m + 1)..(l + m + 1) {
+                k /= i as f64;
+            }
+            let k = k.sqrt();
+
+            let index = l * (l + 1);
+            if m == 0 {
+                result[index] = (k * q) as f32;
+            } else {
+                let k = SQRT_2 * k * q;
+                result[index + m] = (k * c) as f32;
+                result[index - m] = (k * s) as f32;
+            }
+        }
+
+        let (next_c, next_s) = (x * c - y * s, x * s + y * c);
+        c = next_c;
+        s = next_s;
+    }
+}
+
+// Projects f onto the basis of the given order. The result is exact when f is at most the
+// product of two functions of order MAX_ORDER.
+fn project<F: Fn(&Vector3, &[f32]) -> f32>(result: &mut [f32], order: usize, f: F) {
+    let n = order * order;
+    for r in result[..n].iter_mut() {
+        *r = 0.0;
+    }
+
+    let mut basis = [0.0; MAX_COEFFICIENTS];
+    let phi_weight = 2.0 * PI / QUADRATURE_PHI_SAMPLES as f64;
+    for (node, weight) in QUADRATURE_NODES.iter().zip(QUADRATURE_WEIGHTS.iter()) {
+        for &z in [*node, -*node].iter() {
+            let sin_theta = (1.0 - z * z).sqrt();
+            for j in 0..QUADRATURE_PHI_SAMPLES {
+                let phi = phi_weight * (j as f64 + 0.5);
+                let dir = Vector3 {
+                    x: (sin_theta * phi.cos()) as f32,
+                    y: (sin_theta * phi.sin()) as f32,
+                    z: z as f32,
+                };
+                eval_basis(&mut basis, order, &dir);
+
+                let w = (weight * phi_weight) as f32 * f(&dir, &basis[..n]);
+                for (r, b) in result[..n].iter_mut().zip(basis[..n].iter()) {
+                    *r += w * b;
+                }
+            }
+        }
+    }
+}
+
+fn eval(coefficients: &[f32], basis: &[f32]) -> f32 {
+    coefficients.iter().zip(basis.iter()).fold(0.0, |sum, (c, b)| sum + c * b)
+}
+
+/// Evaluates the basis in a normalized direction.
+pub fn eval_direction(result: &mut [f32], order: usize, dir: &Vector3) {
+    check(order, result);
+    eval_basis(result, order, dir);
+}
+
+/// Rotates the function so that what was in direction `d` is found in `d.transform(rotation)`.
+/// The upper 3x3 of `rotation` has to be orthonormal.
+pub fn rotate(result: &mut [f32], order: usize, rotation: &Matrix, input: &[f32]) {
+    check(order, result);
+    check(order, input);
+
+    project(result, order, |dir, _| {
+        // Apply the inverse (transposed) rotation to find where the value came from
+        let row = |i: usize| rotation[i][0] * dir.x + rotation[i][1] * dir.y + rotation[i][2] * dir.z;
+        let source = Vector3 { x: row(0), y: row(1), z: row(2) };
+        let mut basis = [0.0; MAX_COEFFICIENTS];
+        eval_basis(&mut basis, order, &source);
+        eval(&input[..order * order], &basis)
+    });
+}
+
+/// Rotates the function by `angle` radians around the z axis, like `Matrix::rotation_z`.
+pub fn rotate_z(result: &mut [f32], order: usize, angle: f32, input: &[f32]) {
+    check(order, result);
+    check(order, input);
+
+    for l in 0..order {
+        let index = l * (l + 1);
+        result[index] = input[index];
+        for m in 1..(l + 1) {
+            let (sin, cos) = (m as f32 * angle).sin_cos();
+            let (c, s) = (input[index + m], input[index - m]);
+            result[index + m] = c * cos - s * sin;
+            result[index - m] = c * sin + s * cos;
+        }
+    }
+}
+
+pub fn add(result: &mut [f32], order: usize, a: &[f32], b: &[f32]) {
+    check(order, result);
+    check(order, a);
+    check(order, b);
+
+    for i in 0..(order * order) {
+        result[i] = a[i] + b[i];
+    }
+}
+
+pub fn scale(result: &mut [f32], order: usize, input: &[f32], scale: f32) {
+    check(order, result);
+    check(order, input);
+
+    for i in 0..(order * order) {
+        result[i] = input[i] * scale;
+    }
+}
+
+/// The integral of the product of the two functions over the sphere.
+pub fn dot(order: usize, a: &[f32], b: &[f32]) -> f32 {
+    check(order, a);
+    check(order, b);
+
+    eval(&a[..order * order], &b[..order * order])
+}
+
+/// Projects the product of the two functions back onto the basis of the same order.
+pub fn multiply(result: &mut [f32], order: usize, f: &[f32], g: &[f32]) {
+    check(order, result);
+    check(order, f);
+    check(order, g);
+
+    let n = order * order;
+    project(result, order, |_, basis| eval(&f[..n], basis) * eval(&g[..n], basis));
+}
+
+// A light symmetric around `dir`, whose projection onto band l is bands[l] * Y(l, m)(dir). It is
+// scaled so that a white diffuse surface facing the light reflects `color`.
+fn eval_zonal_light(result_r: &mut [f32], result_g: &mut [f32], result_b: &mut [f32], order: usize, dir: &Vector3, bands: &[f32], color: &Vector4) {
+    check(order, result_r);
+    check(order, result_g);
+    check(order, result_b);
+
+    // Irradiance at the surface divided by pi, by the addition theorem
+    let exit_radiance: f32 = (0..order).map(|l| COSINE_LOBE[l] * bands[l] * (2 * l + 1) as f32 / 4.0).sum();
+    let norm = PI as f32 / exit_radiance;
+
+    let mut basis = [0.0; MAX_COEFFICIENTS];
+    eval_basis(&mut basis, order, dir);
+    for (l, band) in bands.iter().enumerate().take(order) {
+        for i in (l * l)..((l + 1) * (l + 1)) {
+            let value = basis[i] * band * norm;
+            result_r[i] = value * color.x;
+            result_g[i] = value * color.y;
+            result_b[i] = value * color.z;
+        }
+    }
+}
+
+// A cap of half angle `angle` around dir projects onto band l as 2pi * bands[l] * Y(l, m)(dir)
+fn cap_bands(order: usize, angle: f32) -> [f32; MAX_ORDER] {
+    // In double precision, the differences cancel out for narrow caps
+    let x = (angle as f64).cos();
+    let mut legendre = [0.0; MAX_ORDER + 1];
+    legendre[0] = 1.0;
+    legendre[1] = x;
+    for l in 1..MAX_ORDER {
+        legendre[l + 1] = ((2 * l + 1) as f64 * x * legendre[l] - l as f64 * legendre[l - 1]) / (l + 1) as f64;
+    }
+
+    // The integral of P(l) from cos(angle) to 1
+    let mut bands = [0.0; MAX_ORDER];
+    bands[0] = (1.0 - x) as f32;
+    for l in 1..order {
+        bands[l] = ((legendre[l - 1] - legendre[l + 1]) / (2 * l + 1) as f64) as f32;
+    }
+    bands
+}
+
+/// Projects a directional light arriving from the normalized direction `dir`.
+pub fn eval_directional_light(result_r: &mut [f32], result_g: &mut [f32], result_b: &mut [f32], order: usize, dir: &Vector3, color: &Vector4) {
+    eval_zonal_light(result_r, result_g, result_b, order, dir, &[1.0; MAX_ORDER], color);
+}
+
+/// Projects a sphere of constant radiance centered at `position`, relative to the origin. The
+/// lighting is constant when the origin is inside the sphere, and a tiny sphere is a directional
+/// light.
+pub fn eval_spherical_light(result_r: &mut [f32], result_g: &mut [f32], result_b: &mut [f32], order: usize, position: &Vector3, radius: f32, color: &Vector4) {
+    let dist = position.length();
+    if dist <= radius {
+        let dir = Vector3 { x: 0.0, y: 0.0, z: 1.0 };
+        eval_zonal_light(result_r, result_g, result_b, order, &dir, &cap_bands(order, PI as f32), color);
+    } else if radius < MIN_LIGHT_RADIUS {
+        eval_directional_light(result_r, result_g, result_b, order, &(*position * (1.0 / dist)), color);
+    } else {
+        let dir = *position * (1.0 / dist);
+        eval_zonal_light(result_r, result_g, result_b, order, &dir, &cap_bands(order, (radius / dist).asin()), color);
+    }
+}
+
+/// Projects a cone of constant radiance around the normalized direction `dir`, with a half angle
+/// of `radius` radians. A tiny cone is a directional light.
+pub fn eval_cone_light(result_r: &mut [f32], result_g: &mut [f32], result_b: &mut [f32], order: usize, dir: &Vector3, radius: f32, color: &Vector4) {
+    if radius < MIN_LIGHT_RADIUS {
+        eval_directional_light(result_r, result_g, result_b, order, dir, color);
+    } else {
+        eval_zonal_light(result_r, result_g, result_b, order, dir, &cap_bands(order, radius), color);
+    }
+}
+
+/// Projects a sky of radiance `top` in the normalized direction `dir` and `bottom` in the
+/// opposite one, linearly interpolated along `dir`.
+pub fn eval_hemisphere_light(result_r: &mut [f32], result_g: &mut [f32], result_b: &mut [f32], order: usize, dir: &Vector3, top: &Vector4, bottom: &Vector4) {
+    check(order, result_r);
+    check(order, result_g);
+    check(order, result_b);
+
+    // A constant average plus half the difference times dot(dir, w), which is a band 1 lobe
+    let mut basis = [0.0; MAX_COEFFICIENTS];
+    eval_basis(&mut basis, order, dir);
+    let constant = (4.0 * PI).sqrt() as f32 * 0.5;
+    let linear = (4.0 * PI / 3.0) as f32 * 0.5;
+
+    let fill = |result: &mut [f32], t: f32, b: f32| {
+        for r in result[..order * order].iter_mut() {
+            *r = 0.0;
+        }
+        result[0] = constant * (t + b);
+        for i in 1..4 {
+            result[i] = linear * (t - b) * basis[i];
+        }
+    };
+    fill(result_r, top.x, bottom.x);
+    fill(result_g, top.y, bottom.y);
+    fill(result_b, top.z, bottom.z);
+}
+
+/// Six square float faces in the order +x, -x, +y, -y, +z, -z, oriented as in Direct3D. Each face
+/// holds `size` * `size` texels row by row from the top left, with `channels` floats per texel
+/// (3 for RGB or 4 for RGBA).
+pub struct CubeMap<'a> {
+    pub size: usize,
+    pub channels: usize,
+    pub faces: [&'a [f32]; 6],
+}
+
+impl<'a> CubeMap<'a> {
+    pub fn new(size: usize, channels: usize, faces: [&'a [f32]; 6]) -> Self {
+        assert!(size > 0);
+        assert!(channels >= 3);
+        for face in faces.iter() {
+            assert_eq!(face.len(), size * size * channels);
+        }
+
+        CubeMap {
+            size,
+            channels,
+            faces,
+        }
+    }
+}
+
+/// Projects the RGB channels of a cube map, weighting every texel by the solid angle it covers.
+pub fn project_cube_map(result_r: &mut [f32], result_g: &mut [f32], result_b: &mut [f32], order: usize, cube_map: &CubeMap) {
+    check(order, result_r);
+    check(order, result_g);
+    check(order, result_b);
+
+    let n = order * order;
+    for i in 0..n {
+        result_r[i] = 0.0;
+        result_g[i] = 0.0;
+        result_b[i] = 0.0;
+    }
+
+    let size = cube_map.size;
+    let step = 2.0 / size as f32;
+    let mut total_weight = 0.0;
+    let mut basis = [0.0; MAX_COEFFICIENTS];
+
+    for (face_index, face) in cube_map.faces.iter().enumerate() {
+        for y in 0..size {
+            // Texel centers, from -1 on the top left to 1 on the bottom right
+            let v = (y as f32 + 0.5) * step - 1.0;
+            for x in 0..size {
+                let u = (x as f32 + 0.5) * step - 1.0;
+                let dir = match face_index {
+                    0 => Vector3 { x: 1.0, y: -v, z: -u },
+                    1 => Vector3 { x: -1.0, y: -v, z: u },
+                    2 => Vector3 { x: u, y: 1.0, z: v },
+                    3 => Vector3 { x: u, y: -1.0, z: -v },
+                    4 => Vector3 { x: u, y: -v, z: 1.0 },
+                    _ => Vector3 { x: -u, y: -v, z: -1.0 },
+                };
+
+                // Solid angle of the texel, projected from the face at distance 1
+                let dist_sq = 1.0 + u * u + v * v;
+                let weight = step * step / (dist_sq * dist_sq.sqrt());
+                total_weight += weight;
+
+                eval_basis(&mut basis, order, &dir.normalize());
+                let texel = &face[(y * size + x) * cube_map.channels..];
+                for i in 0..n {
+                    let b = basis[i] * weight;
+                    result_r[i] += texel[0] * b;
+                    result_g[i] += texel[1] * b;
+                    result_b[i] += texel[2] * b;
+                }
+            }
+        }
+    }
+
+    // The weights add up to slightly less than 4pi, which would darken the result
+    let norm = 4.0 * PI as f32 / total_weight;
+    for i in 0..n {
+        result_r[i] *= norm;
+        result_g[i] *= norm;
+        result_b[i] *= norm;
+    }
+}
@@ -0,0 +1,165 @@
+use glium::uniforms::*;
+use matrix::Matrix;
+use vector::{Vector2, Vector3, Vector4};
+
+// Storage types in the layouts of the DirectXMath XMFLOAT* types. They are loaded into a Matrix
+// for computation, and the parts of the Matrix they cannot hold are filled from the identity.
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Float3x3 {
+    pub m: [[f32; 3]; 3],
+}
+
+/// 4 rows of 3 columns: an affine transform with the translation in the last row.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Float4x3 {
+    pub m: [[f32; 3]; 4],
+}
+
+/// The transpose of `Float4x3`, with the translation in the last column.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Float3x4 {
+    pub m: [[f32; 4]; 3],
+}
+
+#[repr(C, align(16))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Float4x4A {
+    pub m: [[f32; 4]; 4],
+}
+
+impl Float3x3 {
+    pub fn load(&self) -> Matrix {
+        let m = &self.m;
+        Matrix::new(
+            m[0][0], m[0][1], m[0][2], 0.0,
+            m[1][0], m[1][1], m[1][2], 0.0,
+            m[2][0], m[2][1], m[2][2], 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        )
+    }
+
+    pub fn store(m: &Matrix) -> Self {
+        Float3x3 {
+            m: [
+                [m[0][0], m[0][1], m[0][2]],
+                [m[1][0], m[1][1], m[1][2]],
+                [m[2][0], m[2][1], m[2][2]],
+            ]
+        }
+    }
+}
+
+impl Float4x3 {
+    pub fn load(&self) -> Matrix {
+        let m = &self.m;
+        Matrix::new(
+            m[0][0], m[0][1], m[0][2], 0.0,
+            m[1][0], m[1][1], m[1][2], 0.0,
+            m[2][0], m[2][1], m[2][2], 0.0,
+            m[3][0], m[3][1], m[3][2], 1.0,
+        )
+    }
+
+    pub fn store(m: &Matrix) -> Self {
+        Float4x3 {
+            m: [
+                [m[0][0], m[0][1], m[0][2]],
+                [m[1][0], m[1][1], m[1][2]],
+                [m[2][0], m[2][1], m[2][2]],
+                [m[3][0], m[3][1], m[3][2]],
+            ]
+        }
+    }
+}
+
+impl Float3x4 {
+    pub fn load(&self) -> Matrix {
+        let m = &self.m;
+        Matrix::new(
+            m[0][0], m[1][0], m[2][0], 0.0,
+            m[0][1], m[1][1], m[2][1], 0.0,
+            m[0][2], m[1][2], m[2][2], 0.0,
+            m[0][3], m[1][3], m[2][3], 1.0,
+        )
+    }
+
+    pub fn store(m: &Matrix) -> Self {
+        Float3x4 {
+            m: [
+                [m[0][0], m[1][0], m[2][0], m[3][0]],
+                [m[0][1], m[1][1], m[2][1], m[3][1]],
+                [m[0][2], m[1][2], m[2][2], m[3][2]],
+            ]
+        }
+    }
+}
+
+impl Float4x4A {
+    pub fn load(&self) -> Matrix {
+        let m = &self.m;
+        Matrix::new(
+            m[0][0], m[0][1], m[0][2], m[0][3],
+            m[1][0], m[1][1], m[1][2], m[1][3],
+            m[2][0], m[2][1], m[2][2], m[2][3],
+            m[3][0], m[3][1], m[3][2], m[3][3],
+        )
+    }
+
+    pub fn store(m: &Matrix) -> Self {
+        Float4x4A {
+            m: [m[0], m[1], m[2], m[3]]
+        }
+    }
+}
+
+impl AsUniformValue for Float3x3 {
+    fn as_uniform_value(&self) -> UniformValue<'static> {
+        UniformValue::Mat3(self.m)
+    }
+}
+
+impl AsUniformValue for Float4x4A {
+    fn as_uniform_value(&self) -> UniformValue<'static> {
+        UniformValue::Mat4(self.m)
+    }
+}
+
+macro_rules! vector_storage {
+    ($(#[$attr:meta])* $name:ident, $vector:ident, $t:ty, $($c:ident),+) => {
+        $(#[$attr])*
+        pub struct $name {
+            $(pub $c: $t),+
+        }
+
+        impl $name {
+            pub fn load(&self) -> $vector {
+                $vector { $($c: self.$c as f32),+ }
+            }
+
+            pub fn store(v: &$vector) -> Self {
+                $name { $($c: v.$c as $t),+ }
+            }
+        }
+    }
+}
+
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Debug)] Float2, Vector2, f32, x, y);
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Debug)] Float3, Vector3, f32, x, y, z);
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Debug)] Float4, Vector4, f32, x, y, z, w);
+
+vector_storage!(#[repr(C, align(16))] #[derive(Clone, Copy, PartialEq, Debug)] Float2A, Vector2, f32, x, y);
+vector_storage!(#[repr(C, align(16))] #[derive(Clone, Copy, PartialEq, Debug)] Float3A, Vector3, f32, x, y, z);
+vector_storage!(#[repr(C, align(16))] #[derive(Clone, Copy, PartialEq, Debug)] Float4A, Vector4, f32, x, y, z, w);
+
+// Storing to the integer types truncates towards zero and saturates, with NaN stored as 0
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Eq, Debug)] Int2, Vector2, i32, x, y);
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Eq, Debug)] Int3, Vector3, i32, x, y, z);
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Eq, Debug)] Int4, Vector4, i32, x, y, z, w);
+
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Eq, Debug)] UInt2, Vector2, u32, x, y);
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Eq, Debug)] UInt3, Vector3, u32, x, y, z);
+vector_storage!(#[repr(C)] #[derive(Clone, Copy, PartialEq, Eq, Debug)] UInt4, Vector4, u32, x, y, z, w);
@@ -1,5 +1,6 @@
 use matrix::*;
 use std::f32;
+use std::iter::Sum;
 use std::ops::*;
 
 pub trait Vector {
@@ -9,9 +10,16 @@ pub trait Vector {
     fn nan() -> Self;
     fn epsilon() -> Self;
     fn replicate(value: f32) -> Self;
+    /// Negative zero in every component, i.e. only the sign bits are set.
+    fn sign_mask() -> Self where Self: Sized {
+        Self::replicate(-0.0)
+    }
 
+    /// Only the first 2 or 3 indices are used by `Vector2` and `Vector3`, the rest are just
+    /// range checked. Prefer the accessors such as `v.yx()` or `v.zxy()`.
     fn swizzle(&self, e0: usize, e1: usize, e2: usize, e3: usize) -> Self;
-    fn permute(&self, other: &Self, permute_x: usize, permute_y: usize, permute_w: usize, permute_z: usize) -> Self;
+    #[deprecated(note = "use the inherent shuffle::<X, Y, Z, W>, whose indices are checked at compile time")]
+    fn permute(&self, other: &Self, permute_x: usize, permute_y: usize, permute_z: usize, permute_w: usize) -> Self;
 
     fn transform(&self, matrix: &Matrix) -> Self;
 
@@ -32,6 +40,16 @@ pub trait Vector {
     fn splat_w(&self) -> Self;
 }
 
+// XMScalarACosEst
+fn acos_est(value: f32) -> f32 {
+    let x = value.abs();
+    let root = (1.0 - x).max(0.0).sqrt();
+
+    // 3-degree minimax approximation
+    let result = (((-0.0187293 * x + 0.0742610) * x - 0.2121144) * x + 1.5707288) * root;
+    if value >= 0.0 { result } else { f32::consts::PI - result }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Vector2 {
     pub x: f32,
@@ -96,9 +114,11 @@ impl Vector for Vector2 {
         }
     }
 
-    fn swizzle(&self, e0: usize, e1: usize, _e2: usize, _e3: usize) -> Self {
+    fn swizzle(&self, e0: usize, e1: usize, e2: usize, e3: usize) -> Self {
         assert!(e0 < 4);
         assert!(e1 < 4);
+        assert!(e2 < 4);
+        assert!(e3 < 4);
         Vector2 {
             x: self[e0],
             y: self[e1],
@@ -267,10 +287,11 @@ impl Vector for Vector3 {
         }
     }
 
-    fn swizzle(&self, e0: usize, e1: usize, e2: usize, _e3: usize) -> Self {
+    fn swizzle(&self, e0: usize, e1: usize, e2: usize, e3: usize) -> Self {
         assert!(e0 < 4);
         assert!(e1 < 4);
         assert!(e2 < 4);
+        assert!(e3 < 4);
         Vector3 {
             x: self[e0],
             y: self[e1],
@@ -635,6 +656,205 @@ impl Vector for Vector4 {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LineIntersection {
+    Point(Vector2),
+    Parallel,
+    Coincident,
+}
+
+impl Vector2 {
+    pub fn get_x(&self) -> f32 { self.x }
+    pub fn get_y(&self) -> f32 { self.y }
+
+    /// Like XMVectorSetX, the setters return a modified copy and leave `self` unchanged.
+    pub fn set_x(&self, x: f32) -> Self { Vector2 { x, ..*self } }
+    pub fn set_y(&self, y: f32) -> Self { Vector2 { y, ..*self } }
+
+    pub fn orthogonal(&self) -> Self {
+        Vector2 {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Z component of the cross product of the two vectors, extended with z = 0.
+    pub fn cross(&self, other: &Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn intersect_line(line1_point1: &Vector2, line1_point2: &Vector2, line2_point1: &Vector2, line2_point2: &Vector2) -> LineIntersection {
+        let v1 = *line1_point2 - *line1_point1;
+        let v2 = *line2_point2 - *line2_point1;
+        let v3 = *line1_point1 - *line2_point1;
+
+        let c1 = v1.cross(&v2);
+        let c2 = v2.cross(&v3);
+
+        if c1.abs() <= f32::EPSILON {
+            if c2.abs() <= f32::EPSILON {
+                LineIntersection::Coincident
+            } else {
+                LineIntersection::Parallel
+            }
+        } else {
+            LineIntersection::Point(*line1_point1 + v1 * (c2 / c1))
+        }
+    }
+
+    pub fn line_point_distance(line_point1: &Vector2, line_point2: &Vector2, point: &Vector2) -> f32 {
+        let point_vector = *point - *line_point1;
+        let line_vector = *line_point2 - *line_point1;
+
+        let scale = point_vector.dot(&line_vector) / line_vector.length_sq();
+        (point_vector - line_vector * scale).length()
+    }
+}
+
+impl Vector3 {
+    pub fn get_x(&self) -> f32 { self.x }
+    pub fn get_y(&self) -> f32 { self.y }
+    pub fn get_z(&self) -> f32 { self.z }
+
+    pub fn set_x(&self, x: f32) -> Self { Vector3 { x, ..*self } }
+    pub fn set_y(&self, y: f32) -> Self { Vector3 { y, ..*self } }
+    pub fn set_z(&self, z: f32) -> Self { Vector3 { z, ..*self } }
+
+    /// Like XMVector3Orthogonal, except that z is +x rather than -x when y and z have different
+    /// signs, where XMVector3Orthogonal does not return an orthogonal vector.
+    pub fn orthogonal(&self) -> Self {
+        if (self.z < 0.0) == (self.y < 0.0) {
+            Vector3 {
+                x: self.y + self.z,
+                y: -self.x,
+                z: -self.x,
+            }
+        } else {
+            Vector3 {
+                x: self.y - self.z,
+                y: -self.x,
+                z: self.x,
+            }
+        }
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Vector3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Transforms the point (x, y, z, 1) and projects the result back into w = 1.
+    pub fn transform_coord(&self, matrix: &Matrix) -> Self {
+        let x = self.x * matrix[0][0] + self.y * matrix[1][0] + self.z * matrix[2][0] + matrix[3][0];
+        let y = self.x * matrix[0][1] + self.y * matrix[1][1] + self.z * matrix[2][1] + matrix[3][1];
+        let z = self.x * matrix[0][2] + self.y * matrix[1][2] + self.z * matrix[2][2] + matrix[3][2];
+        let w = self.x * matrix[0][3] + self.y * matrix[1][3] + self.z * matrix[2][3] + matrix[3][3];
+        Vector3 {
+            x: x / w,
+            y: y / w,
+            z: z / w,
+        }
+    }
+
+    pub fn line_point_distance(line_point1: &Vector3, line_point2: &Vector3, point: &Vector3) -> f32 {
+        let point_vector = *point - *line_point1;
+        let line_vector = *line_point2 - *line_point1;
+
+        let scale = point_vector.dot(&line_vector) / line_vector.length_sq();
+        (point_vector - line_vector * scale).length()
+    }
+}
+
+impl Vector4 {
+    pub fn get_x(&self) -> f32 { self.x }
+    pub fn get_y(&self) -> f32 { self.y }
+    pub fn get_z(&self) -> f32 { self.z }
+    pub fn get_w(&self) -> f32 { self.w }
+
+    pub fn set_x(&self, x: f32) -> Self { Vector4 { x, ..*self } }
+    pub fn set_y(&self, y: f32) -> Self { Vector4 { y, ..*self } }
+    pub fn set_z(&self, z: f32) -> Self { Vector4 { z, ..*self } }
+    pub fn set_w(&self, w: f32) -> Self { Vector4 { w, ..*self } }
+
+    pub fn orthogonal(&self) -> Self {
+        Vector4 {
+            x: self.z,
+            y: self.w,
+            z: -self.x,
+            w: -self.y,
+        }
+    }
+}
+
+// Length, angle and projection queries, which only need the dot product
+macro_rules! geometric_queries {
+    ($V:ident, $first:ident $(, $c:ident)*) => {
+        impl $V {
+            pub fn dot(&self, other: &Self) -> f32 {
+                self.$first * other.$first $(+ self.$c * other.$c)*
+            }
+
+            pub fn length_sq(&self) -> f32 {
+                self.dot(self)
+            }
+            pub fn length(&self) -> f32 {
+                self.length_sq().sqrt()
+            }
+            /// Zero-length vectors are returned unchanged.
+            pub fn normalize(&self) -> Self {
+                let length = self.length();
+                if length > 0.0 { *self * (1.0 / length) } else { *self }
+            }
+
+            pub fn clamp_length(&self, min: f32, max: f32) -> Self {
+                assert!(0.0 <= min);
+                assert!(min <= max);
+                let length = self.length();
+                if length > max {
+                    *self * (max / length)
+                } else if 0.0 < length && length < min {
+                    *self * (min / length)
+                } else {
+                    *self
+                }
+            }
+            /// Every component of `min` and `max` must hold the same value.
+            pub fn clamp_length_v(&self, min: &Self, max: &Self) -> Self {
+                assert!(*min == min.splat_x());
+                assert!(*max == max.splat_x());
+                self.clamp_length(min.$first, max.$first)
+            }
+
+            /// Both vectors must be normalized.
+            pub fn angle_between_normals(&self, other: &Self) -> f32 {
+                self.dot(other).clamp(-1.0, 1.0).acos()
+            }
+            pub fn angle_between_normals_est(&self, other: &Self) -> f32 {
+                acos_est(self.dot(other).clamp(-1.0, 1.0))
+            }
+            pub fn angle_between_vectors(&self, other: &Self) -> f32 {
+                let cos_angle = self.dot(other) / (self.length() * other.length());
+                cos_angle.clamp(-1.0, 1.0).acos()
+            }
+
+            /// Splits `self` into the parts parallel and perpendicular to `normal`, which must be
+            /// normalized.
+            pub fn components_from_normal(&self, normal: &Self) -> (Self, Self) {
+                let parallel = *normal * self.dot(normal);
+                let perpendicular = *self - parallel;
+                (parallel, perpendicular)
+            }
+        }
+    }
+}
+
+geometric_queries!(Vector2, x, y);
+geometric_queries!(Vector3, x, y, z);
+geometric_queries!(Vector4, x, y, z, w);
+
 impl Add for Vector2 {
     type Output = Vector2;
     fn add(self, rhs: Vector2) -> Self::Output {
@@ -913,3 +1133,102 @@ impl Index<usize> for Vector4 {
         }
     }
 }
+
+// Implements `$op` for every combination of references, in terms of the impl on values
+macro_rules! forward_ref_binop {
+    ($op:ident, $method:ident, $lhs:ty, $rhs:ty) => {
+        impl<'a> $op<$rhs> for &'a $lhs {
+            type Output = <$lhs as $op<$rhs>>::Output;
+            fn $method(self, rhs: $rhs) -> Self::Output { (*self).$method(rhs) }
+        }
+        impl<'a> $op<&'a $rhs> for $lhs {
+            type Output = <$lhs as $op<$rhs>>::Output;
+            fn $method(self, rhs: &'a $rhs) -> Self::Output { self.$method(*rhs) }
+        }
+        impl<'a, 'b> $op<&'a $rhs> for &'b $lhs {
+            type Output = <$lhs as $op<$rhs>>::Output;
+            fn $method(self, rhs: &'a $rhs) -> Self::Output { (*self).$method(*rhs) }
+        }
+    }
+}
+
+macro_rules! assign_op {
+    ($op:ident, $method:ident, $binop_method:ident, $lhs:ty, $rhs:ty) => {
+        impl $op<$rhs> for $lhs {
+            fn $method(&mut self, rhs: $rhs) { *self = (*self).$binop_method(rhs); }
+        }
+        impl<'a> $op<&'a $rhs> for $lhs {
+            fn $method(&mut self, rhs: &'a $rhs) { *self = (*self).$binop_method(*rhs); }
+        }
+    }
+}
+
+macro_rules! vector_ops {
+    ($V:ident) => {
+        impl Div<f32> for $V {
+            type Output = $V;
+            fn div(self, rhs: f32) -> Self::Output { self / $V::replicate(rhs) }
+        }
+
+        impl Mul<$V> for f32 {
+            type Output = $V;
+            fn mul(self, rhs: $V) -> Self::Output { rhs * self }
+        }
+
+        /// Same as `transform`.
+        impl<'a> Mul<&'a Matrix> for $V {
+            type Output = $V;
+            fn mul(self, rhs: &'a Matrix) -> Self::Output { self.transform(rhs) }
+        }
+        impl Mul<Matrix> for $V {
+            type Output = $V;
+            fn mul(self, rhs: Matrix) -> Self::Output { self.transform(&rhs) }
+        }
+        impl<'a, 'b> Mul<&'a Matrix> for &'b $V {
+            type Output = $V;
+            fn mul(self, rhs: &'a Matrix) -> Self::Output { self.transform(rhs) }
+        }
+        impl<'a> Mul<Matrix> for &'a $V {
+            type Output = $V;
+            fn mul(self, rhs: Matrix) -> Self::Output { self.transform(&rhs) }
+        }
+
+        impl<'a> Neg for &'a $V {
+            type Output = $V;
+            fn neg(self) -> Self::Output { -*self }
+        }
+
+        forward_ref_binop!(Add, add, $V, $V);
+        forward_ref_binop!(Sub, sub, $V, $V);
+        forward_ref_binop!(Mul, mul, $V, $V);
+        forward_ref_binop!(Div, div, $V, $V);
+        forward_ref_binop!(Mul, mul, $V, f32);
+        forward_ref_binop!(Div, div, $V, f32);
+        forward_ref_binop!(Mul, mul, f32, $V);
+
+        assign_op!(AddAssign, add_assign, add, $V, $V);
+        assign_op!(SubAssign, sub_assign, sub, $V, $V);
+        assign_op!(MulAssign, mul_assign, mul, $V, $V);
+        assign_op!(DivAssign, div_assign, div, $V, $V);
+        assign_op!(MulAssign, mul_assign, mul, $V, f32);
+        assign_op!(DivAssign, div_assign, div, $V, f32);
+
+        impl MulAssign<Matrix> for $V {
+            fn mul_assign(&mut self, rhs: Matrix) { *self = self.transform(&rhs); }
+        }
+        impl<'a> MulAssign<&'a Matrix> for $V {
+            fn mul_assign(&mut self, rhs: &'a Matrix) { *self = self.transform(rhs); }
+        }
+
+        impl Sum for $V {
+            fn sum<I: Iterator<Item = $V>>(iter: I) -> Self { iter.fold($V::zero(), Add::add) }
+        }
+        impl<'a> Sum<&'a $V> for $V {
+            fn sum<I: Iterator<Item = &'a $V>>(iter: I) -> Self { iter.fold($V::zero(), Add::add) }
+        }
+    }
+}
+
+vector_ops!(Vector2);
+vector_ops!(Vector3);
+vector_ops!(Vector4);
@@ -0,0 +1,69 @@
+use matrix::Matrix;
+use vector::Vector3;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub min_z: f32,
+    pub max_z: f32,
+}
+
+impl Viewport {
+    pub fn new(x: f32, y: f32, width: f32, height: f32, min_z: f32, max_z: f32) -> Self {
+        Viewport { x, y, width, height, min_z, max_z }
+    }
+
+    fn project(&self, v: &Vector3, transform: &Matrix) -> Vector3 {
+        let half_width = self.width * 0.5;
+        let half_height = self.height * 0.5;
+
+        let v = v.transform_coord(transform);
+        Vector3 {
+            x: v.x * half_width + self.x + half_width,
+            y: v.y * -half_height + self.y + half_height,
+            z: v.z * (self.max_z - self.min_z) + self.min_z,
+        }
+    }
+
+    fn unproject(&self, v: &Vector3, inverse_transform: &Matrix) -> Vector3 {
+        let v = Vector3 {
+            x: (v.x - self.x) / (self.width * 0.5) - 1.0,
+            y: (v.y - self.y) / (self.height * -0.5) + 1.0,
+            z: (v.z - self.min_z) / (self.max_z - self.min_z),
+        };
+        v.transform_coord(inverse_transform)
+    }
+}
+
+impl Vector3 {
+    /// Object space to screen space.
+    pub fn project(&self, viewport: &Viewport, projection: &Matrix, view: &Matrix, world: &Matrix) -> Self {
+        let transform = world * view * projection;
+        viewport.project(self, &transform)
+    }
+
+    /// Screen space to object space.
+    pub fn unproject(&self, viewport: &Viewport, projection: &Matrix, view: &Matrix, world: &Matrix) -> Self {
+        let transform = (world * view * projection).inverse();
+        viewport.unproject(self, &transform)
+    }
+
+    pub fn project_stream(output: &mut [Vector3], input: &[Vector3], viewport: &Viewport, projection: &Matrix, view: &Matrix, world: &Matrix) {
+        assert_eq!(output.len(), input.len());
+        let transform = world * view * projection;
+        for (o, i) in output.iter_mut().zip(input.iter()) {
+            *o = viewport.project(i, &transform);
+        }
+    }
+
+    pub fn unproject_stream(output: &mut [Vector3], input: &[Vector3], viewport: &Viewport, projection: &Matrix, view: &Matrix, world: &Matrix) {
+        assert_eq!(output.len(), input.len());
+        let transform = (world * view * projection).inverse();
+        for (o, i) in output.iter_mut().zip(input.iter()) {
+            *o = viewport.unproject(i, &transform);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector3, Vector4, Matrix};
+use xmath::{BoundingSphere, BoundingBox, BoundingOrientedBox, BoundingFrustum, ContainmentType, PlaneIntersectionType};
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn unit_box() -> BoundingBox {
+    BoundingBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0))
+}
+
+#[test]
+fn new_box() {
+    let b = BoundingBox::new(v(1.0, 2.0, 3.0), v(4.0, 5.0, 6.0));
+
+    assert_eq!(b.center, v(1.0, 2.0, 3.0));
+    assert_eq!(b.extents, v(4.0, 5.0, 6.0));
+    assert_eq!(b.min(), v(-3.0, -3.0, -3.0));
+    assert_eq!(b.max(), v(5.0, 7.0, 9.0));
+}
+
+#[test]
+fn create_from_corners() {
+    let b = BoundingBox::create_from_corners(&v(2.0, -1.0, 4.0), &v(0.0, 1.0, -2.0));
+
+    assert_eq!(b.center, v(1.0, 0.0, 1.0));
+    assert_eq!(b.extents, v(1.0, 1.0, 3.0));
+}
+
+#[test]
+fn create_from_points() {
+    let points = [
+        v(1.0, 0.0, 0.0),
+        v(-1.0, 2.0, 0.0),
+        v(0.0, 0.5, 3.0),
+        v(0.3, -2.0, -1.0),
+    ];
+    let b = BoundingBox::create_from_points(&points);
+
+    assert_eq!(b.center, v(0.0, 0.0, 1.0));
+    assert_eq!(b.extents, v(1.0, 2.0, 2.0));
+}
+
+#[test]
+fn create_from_sphere() {
+    let b = BoundingBox::create_from_sphere(&BoundingSphere::new(v(1.0, 2.0, 3.0), 2.0));
+
+    assert_eq!(b.center, v(1.0, 2.0, 3.0));
+    assert_eq!(b.extents, v(2.0, 2.0, 2.0));
+}
+
+#[test]
+fn create_merged() {
+    let b1 = BoundingBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+    let b2 = BoundingBox::new(v(3.0, 0.0, 0.0), v(1.0, 2.0, 0.5));
+    let merged = BoundingBox::create_merged(&b1, &b2);
+
+    assert_eq!(merged.center, v(1.5, 0.0, 0.0));
+    assert_eq!(merged.extents, v(2.5, 2.0, 1.0));
+}
+
+#[test]
+fn get_corners() {
+    let corners = BoundingBox::new(v(1.0, 1.0, 1.0), v(1.0, 2.0, 3.0)).get_corners();
+
+    assert_eq!(corners[0], v(0.0, -1.0, 4.0));
+    assert_eq!(corners[2], v(2.0, 3.0, 4.0));
+    assert_eq!(corners[4], v(0.0, -1.0, -2.0));
+    assert_eq!(corners[6], v(2.0, 3.0, -2.0));
+    for i in 0..8 {
+        for j in (i + 1)..8 {
+            assert!(corners[i] != corners[j]);
+        }
+    }
+}
+
+#[test]
+fn transform_encloses_rotated_box() {
+    let b = BoundingBox::new(v(1.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+    let transformed = b.transform(&(Matrix::rotation_z(f32::consts::FRAC_PI_4) * Matrix::translation(0.0, 0.0, 2.0)));
+    let s = f32::consts::SQRT_2;
+
+    assert!((transformed.center.x - s * 0.5).abs() < 1e-5);
+    assert!((transformed.center.y - s * 0.5).abs() < 1e-5);
+    assert!((transformed.center.z - 2.0).abs() < 1e-5);
+    assert!((transformed.extents.x - s).abs() < 1e-5);
+    assert!((transformed.extents.y - s).abs() < 1e-5);
+    assert!((transformed.extents.z - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn transform_by_scale() {
+    let b = BoundingBox::new(v(1.0, 1.0, 1.0), v(1.0, 1.0, 1.0));
+    let transformed = b.transform(&Matrix::new(
+        2.0, 0.0, 0.0, 0.0,
+        0.0, -1.0, 0.0, 0.0,
+        0.0, 0.0, 0.5, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ));
+
+    assert_eq!(transformed.center, v(2.0, -1.0, 0.5));
+    assert_eq!(transformed.extents, v(2.0, 1.0, 0.5));
+}
+
+#[test]
+fn contains_point() {
+    let b = unit_box();
+
+    assert_eq!(b.contains_point(&v(0.5, -0.5, 1.0)), ContainmentType::Contains);
+    assert_eq!(b.contains_point(&v(0.5, -0.5, 1.1)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_triangle() {
+    let b = unit_box();
+
+    assert_eq!(b.contains_triangle(&v(0.0, 0.0, 0.0), &v(1.0, 0.0, 0.0), &v(0.0, 1.0, 1.0)), ContainmentType::Contains);
+    assert_eq!(b.contains_triangle(&v(0.0, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(0.0, 3.0, 0.0)), ContainmentType::Intersects);
+    assert_eq!(b.contains_triangle(&v(2.0, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(2.0, 1.0, 0.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_sphere() {
+    let b = unit_box();
+
+    assert_eq!(b.contains_sphere(&BoundingSphere::new(v(0.0, 0.0, 0.0), 1.0)), ContainmentType::Contains);
+    assert_eq!(b.contains_sphere(&BoundingSphere::new(v(0.5, 0.0, 0.0), 1.0)), ContainmentType::Intersects);
+    assert_eq!(b.contains_sphere(&BoundingSphere::new(v(1.9, 1.9, 0.0), 1.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_box() {
+    let b = unit_box();
+
+    assert_eq!(b.contains_box(&BoundingBox::new(v(0.5, 0.5, 0.5), v(0.5, 0.5, 0.5))), ContainmentType::Contains);
+    assert_eq!(b.contains_box(&b), ContainmentType::Contains);
+    assert_eq!(b.contains_box(&BoundingBox::new(v(1.5, 0.0, 0.0), v(1.0, 1.0, 1.0))), ContainmentType::Intersects);
+    assert_eq!(b.contains_box(&BoundingBox::new(v(3.0, 0.0, 0.0), v(1.0, 1.0, 1.0))), ContainmentType::Disjoint);
+}
+
+#[test]
+fn intersects_oriented_box() {
+    let b = unit_box();
+    let rotation = Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 1.0), f32::consts::FRAC_PI_4);
+
+    assert!(b.intersects_oriented_box(&BoundingOrientedBox::new(v(2.3, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation)));
+    assert!(!b.intersects_oriented_box(&BoundingOrientedBox::new(v(2.3, 2.3, 0.0), v(1.0, 1.0, 1.0), rotation)));
+}
+
+#[test]
+fn intersects_sphere() {
+    let b = unit_box();
+
+    assert!(b.intersects_sphere(&BoundingSphere::new(v(1.5, 1.5, 0.0), 1.0)));
+    assert!(!b.intersects_sphere(&BoundingSphere::new(v(1.8, 1.8, 0.0), 1.0)));
+}
+
+#[test]
+fn intersects_box() {
+    let b = unit_box();
+
+    assert!(b.intersects_box(&BoundingBox::new(v(2.0, 0.0, 0.0), v(1.0, 1.0, 1.0))));
+    assert!(b.intersects_box(&BoundingBox::new(v(0.0, 0.0, 0.0), v(0.1, 0.1, 0.1))));
+    assert!(!b.intersects_box(&BoundingBox::new(v(2.0, 2.0, 2.1), v(1.0, 1.0, 1.0))));
+}
+
+#[test]
+fn intersects_triangle() {
+    let b = unit_box();
+
+    // Triangle through the box
+    assert!(b.intersects_triangle(&v(-5.0, -5.0, 0.0), &v(5.0, -5.0, 0.0), &v(0.0, 5.0, 0.0)));
+    // Overlapping bounds, separated by the triangle plane
+    assert!(!b.intersects_triangle(&v(3.5, 0.0, 0.0), &v(0.0, 3.5, 0.0), &v(0.0, 0.0, 3.5)));
+    assert!(b.intersects_triangle(&v(2.5, 0.0, 0.0), &v(0.0, 2.5, 0.0), &v(0.0, 0.0, 2.5)));
+    // Overlapping bounds, separated by an edge axis
+    assert!(!b.intersects_triangle(&v(2.0, 0.5, 0.0), &v(0.5, 2.0, 0.0), &v(1.3, 1.3, 0.1)));
+    // Disjoint bounds
+    assert!(!b.intersects_triangle(&v(2.0, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(2.0, 1.0, 0.0)));
+}
+
+#[test]
+fn intersects_degenerate_triangle() {
+    let b = unit_box();
+
+    assert!(b.intersects_triangle(&v(-5.0, 0.0, 0.0), &v(5.0, 0.0, 0.0), &v(0.0, 0.0, 0.0)));
+    assert!(!b.intersects_triangle(&v(2.0, 0.5, 0.0), &v(0.5, 2.0, 0.0), &v(1.25, 1.25, 0.0)));
+    assert!(b.intersects_triangle(&v(0.5, 0.5, 0.5), &v(0.5, 0.5, 0.5), &v(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn intersects_plane() {
+    let b = BoundingBox::new(v(0.0, 3.0, 0.0), v(1.0, 1.0, 1.0));
+    let s = 0.5f32.sqrt();
+    let plane = |d: f32| Vector4 { x: 0.0, y: s, z: s, w: d };
+
+    assert_eq!(b.intersects_plane(&plane(0.0)), PlaneIntersectionType::Front);
+    assert_eq!(b.intersects_plane(&plane(-3.0 * s)), PlaneIntersectionType::Intersecting);
+    assert_eq!(b.intersects_plane(&plane(-10.0)), PlaneIntersectionType::Back);
+}
+
+#[test]
+fn intersects_ray() {
+    let b = BoundingBox::new(v(0.0, 0.0, 5.0), v(1.0, 1.0, 1.0));
+    let origin = v(0.0, 0.0, 0.0);
+
+    assert_eq!(b.intersects_ray(&origin, &v(0.0, 0.0, 1.0)), Some(4.0));
+    assert_eq!(b.intersects_ray(&v(0.5, 0.5, 0.0), &v(0.0, 0.0, 1.0)), Some(4.0));
+    assert_eq!(b.intersects_ray(&origin, &v(0.0, 0.0, -1.0)), None);
+    assert_eq!(b.intersects_ray(&v(2.0, 0.0, 0.0), &v(0.0, 0.0, 1.0)), None);
+    assert_eq!(b.intersects_ray(&v(0.0, 0.0, 5.0), &v(1.0, 0.0, 0.0)), Some(-1.0));
+}
+
+#[test]
+fn intersects_diagonal_ray() {
+    let b = BoundingBox::new(v(5.0, 5.0, 0.0), v(1.0, 1.0, 1.0));
+    let s = 0.5f32.sqrt();
+
+    let dist = b.intersects_ray(&v(0.0, 0.0, 0.0), &v(s, s, 0.0)).unwrap();
+    assert!((dist - 4.0 * f32::consts::SQRT_2).abs() < 1e-5);
+    assert_eq!(b.intersects_ray(&v(0.0, 0.0, 0.0), &v(s, -s, 0.0)), None);
+}
+
+#[test]
+fn intersects_frustum() {
+    let f = BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0));
+
+    assert!(BoundingBox::new(v(0.0, 0.0, -1.0), v(1.0, 1.0, 1.0)).intersects_frustum(&f));
+    assert!(!BoundingBox::new(v(0.0, 0.0, -1.0), v(1.0, 1.0, 1.0)).intersects_frustum(&f.transform(&Matrix::translation(0.0, 0.0, 10.0))));
+}
@@ -0,0 +1,293 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector, Vector3, Vector4, Matrix};
+use xmath::{BoundingSphere, BoundingBox, BoundingOrientedBox, BoundingFrustum, ContainmentType, PlaneIntersectionType};
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn near(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+fn assert_near(a: &Vector3, b: &Vector3) {
+    assert!((*a - *b).length() < 1e-4, "{:?} != {:?}", a, b);
+}
+
+// 90 degree square frustum looking down -z from 1 to 10
+fn frustum() -> BoundingFrustum {
+    BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0))
+}
+
+#[test]
+fn create_from_perspective_fov() {
+    let f = BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 2.0, 0.5, 100.0));
+
+    assert_eq!(f.origin, Vector3::zero());
+    assert_eq!(f.orientation, Vector4::quaternion_identity());
+    assert!(near(f.right_slope, 2.0));
+    assert!(near(f.left_slope, -2.0));
+    assert!(near(f.top_slope, 1.0));
+    assert!(near(f.bottom_slope, -1.0));
+    assert!(near(f.near, 0.5));
+    assert!((f.far - 100.0).abs() < 1e-2);
+}
+
+#[test]
+fn create_from_perspective() {
+    let f = BoundingFrustum::create_from_matrix(&Matrix::perspective(4.0, 2.0, 2.0, 20.0));
+
+    assert!(near(f.right_slope, 1.0));
+    assert!(near(f.left_slope, -1.0));
+    assert!(near(f.top_slope, 0.5));
+    assert!(near(f.bottom_slope, -0.5));
+    assert!(near(f.near, 2.0));
+    assert!(near(f.far, 20.0));
+}
+
+#[test]
+fn create_from_left_handed_matrix() {
+    // perspective_fov with the z axis flipped
+    let flip = Matrix::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, -1.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    );
+    let f = BoundingFrustum::create_from_matrix(&(flip * Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0)));
+
+    assert_eq!(f.contains_point(&v(0.0, 0.0, 5.0)), ContainmentType::Contains);
+    assert_eq!(f.contains_point(&v(4.5, -4.5, 5.0)), ContainmentType::Contains);
+    assert_eq!(f.contains_point(&v(0.0, 0.0, -5.0)), ContainmentType::Disjoint);
+    assert!(near(f.near, 1.0));
+    assert!(near(f.far, 10.0));
+}
+
+#[test]
+fn create_from_reversed_depth_matrix() {
+    // Maps the near plane to depth 1 and the far plane to depth 0
+    let reverse = Matrix::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, -1.0, 0.0,
+        0.0, 0.0, 1.0, 1.0,
+    );
+    let f = BoundingFrustum::create_from_matrix(&(Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0) * reverse));
+
+    assert!(near(f.near, 1.0));
+    assert!(near(f.far, 10.0));
+    assert!(near(f.right_slope, 1.0));
+}
+
+#[test]
+fn get_corners() {
+    let corners = frustum().get_corners();
+
+    assert_near(&corners[0], &v(-1.0, 1.0, -1.0));
+    assert_near(&corners[1], &v(1.0, 1.0, -1.0));
+    assert_near(&corners[2], &v(1.0, -1.0, -1.0));
+    assert_near(&corners[3], &v(-1.0, -1.0, -1.0));
+    assert_near(&corners[4], &v(-10.0, 10.0, -10.0));
+    assert_near(&corners[5], &v(10.0, 10.0, -10.0));
+    assert_near(&corners[6], &v(10.0, -10.0, -10.0));
+    assert_near(&corners[7], &v(-10.0, -10.0, -10.0));
+}
+
+#[test]
+fn corners_project_to_clip_space_corners() {
+    let projection = Matrix::perspective_fov(1.1, 1.6, 0.3, 40.0);
+    let corners = BoundingFrustum::create_from_matrix(&projection).get_corners();
+
+    assert_near(&corners[1].transform_coord(&projection), &v(1.0, 1.0, 0.0));
+    assert_near(&corners[7].transform_coord(&projection), &v(-1.0, -1.0, 1.0));
+}
+
+#[test]
+fn get_planes() {
+    let planes = frustum().get_planes();
+    let s = 0.5f32.sqrt();
+    let expected = [
+        Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 1.0 },
+        Vector4 { x: 0.0, y: 0.0, z: -1.0, w: -10.0 },
+        Vector4 { x: s, y: 0.0, z: s, w: 0.0 },
+        Vector4 { x: -s, y: 0.0, z: s, w: 0.0 },
+        Vector4 { x: 0.0, y: s, z: s, w: 0.0 },
+        Vector4 { x: 0.0, y: -s, z: s, w: 0.0 },
+    ];
+
+    for (p, e) in planes.iter().zip(expected.iter()) {
+        assert!((*p - *e).length() < 1e-4, "{:?} != {:?}", p, e);
+    }
+}
+
+#[test]
+fn planes_contain_corners() {
+    let f = frustum().transform(&(Matrix::rotation_y(0.7) * Matrix::translation(1.0, 2.0, 3.0)));
+    let planes = f.get_planes();
+
+    for c in f.get_corners().iter() {
+        for p in planes.iter() {
+            assert!(p.x * c.x + p.y * c.y + p.z * c.z + p.w < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn transform() {
+    let m = Matrix::new(
+        2.0, 0.0, 0.0, 0.0,
+        0.0, 2.0, 0.0, 0.0,
+        0.0, 0.0, 2.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ) * Matrix::rotation_y(0.5) * Matrix::translation(1.0, 2.0, 3.0);
+    let f = frustum();
+    let transformed = f.transform(&m);
+
+    assert_near(&transformed.origin, &v(1.0, 2.0, 3.0));
+    assert!(near(transformed.near, 2.0));
+    assert!(near(transformed.far, 20.0));
+    assert_eq!(transformed.right_slope, f.right_slope);
+    for (c, expected) in transformed.get_corners().iter().zip(f.get_corners().iter()) {
+        assert_near(c, &expected.transform(&m));
+    }
+}
+
+#[test]
+fn contains_point() {
+    let f = frustum();
+
+    assert_eq!(f.contains_point(&v(0.0, 0.0, -5.0)), ContainmentType::Contains);
+    assert_eq!(f.contains_point(&v(4.9, -4.9, -5.0)), ContainmentType::Contains);
+    assert_eq!(f.contains_point(&v(5.1, 0.0, -5.0)), ContainmentType::Disjoint);
+    assert_eq!(f.contains_point(&v(0.0, 0.0, -0.5)), ContainmentType::Disjoint);
+    assert_eq!(f.contains_point(&v(0.0, 0.0, -11.0)), ContainmentType::Disjoint);
+    assert_eq!(f.contains_point(&v(0.0, 0.0, 5.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_triangle() {
+    let f = frustum();
+
+    assert_eq!(f.contains_triangle(&v(0.0, 0.0, -2.0), &v(1.0, 0.0, -5.0), &v(0.0, 1.0, -5.0)), ContainmentType::Contains);
+    assert_eq!(f.contains_triangle(&v(0.0, 0.0, -5.0), &v(20.0, 0.0, -5.0), &v(0.0, 1.0, -5.0)), ContainmentType::Intersects);
+    // Surrounds the frustum without touching it
+    assert_eq!(f.contains_triangle(&v(-100.0, -100.0, 1.0), &v(100.0, -100.0, 1.0), &v(0.0, 100.0, 1.0)), ContainmentType::Disjoint);
+    // Beside the apex, inside the infinite pyramid's bounding box
+    assert_eq!(f.contains_triangle(&v(3.0, 0.0, -2.0), &v(4.0, 0.0, -2.0), &v(3.0, 1.0, -2.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_sphere() {
+    let f = frustum();
+
+    assert_eq!(f.contains_sphere(&BoundingSphere::new(v(0.0, 0.0, -5.0), 1.0)), ContainmentType::Contains);
+    assert_eq!(f.contains_sphere(&BoundingSphere::new(v(5.0, 0.0, -5.0), 1.0)), ContainmentType::Intersects);
+    assert_eq!(f.contains_sphere(&BoundingSphere::new(v(8.0, 0.0, -5.0), 1.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_box() {
+    let f = frustum();
+
+    assert_eq!(f.contains_box(&BoundingBox::new(v(0.0, 0.0, -5.0), v(1.0, 1.0, 1.0))), ContainmentType::Contains);
+    assert_eq!(f.contains_box(&BoundingBox::new(v(0.0, 0.0, -10.0), v(1.0, 1.0, 1.0))), ContainmentType::Intersects);
+    assert_eq!(f.contains_box(&BoundingBox::new(v(0.0, 0.0, 2.0), v(1.0, 1.0, 1.0))), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_oriented_box() {
+    let f = frustum();
+    let rotation = Vector4::quaternion_rotation_axis(&v(1.0, 1.0, 0.0), 0.8);
+
+    assert_eq!(f.contains_oriented_box(&BoundingOrientedBox::new(v(0.0, 0.0, -5.0), v(1.0, 1.0, 1.0), rotation)), ContainmentType::Contains);
+    assert_eq!(f.contains_oriented_box(&BoundingOrientedBox::new(v(5.0, 0.0, -5.0), v(1.0, 1.0, 1.0), rotation)), ContainmentType::Intersects);
+    assert_eq!(f.contains_oriented_box(&BoundingOrientedBox::new(v(9.0, 0.0, -5.0), v(1.0, 1.0, 1.0), rotation)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_frustum() {
+    let f = frustum();
+    let inner = BoundingFrustum { near: 2.0, far: 5.0, ..f };
+    let behind = f.transform(&Matrix::translation(0.0, 0.0, 12.0));
+
+    assert_eq!(f.contains_frustum(&f), ContainmentType::Contains);
+    assert_eq!(f.contains_frustum(&inner), ContainmentType::Contains);
+    assert_eq!(inner.contains_frustum(&f), ContainmentType::Intersects);
+    assert_eq!(f.contains_frustum(&behind), ContainmentType::Disjoint);
+}
+
+#[test]
+fn intersects_sphere() {
+    let f = frustum();
+
+    assert!(f.intersects_sphere(&BoundingSphere::new(v(0.0, 0.0, -5.0), 1.0)));
+    assert!(f.intersects_sphere(&BoundingSphere::new(v(0.0, 0.0, 0.0), 1.1)));
+    assert!(!f.intersects_sphere(&BoundingSphere::new(v(0.0, 0.0, 0.0), 0.9)));
+    // Near a far corner, in front of three planes but outside the frustum
+    assert!(!f.intersects_sphere(&BoundingSphere::new(v(10.8, 10.8, -10.8), 1.0)));
+    assert!(f.intersects_sphere(&BoundingSphere::new(v(10.5, 10.5, -10.5), 1.0)));
+    // Outside a single face
+    assert!(f.intersects_sphere(&BoundingSphere::new(v(0.0, 0.0, -10.9), 1.0)));
+}
+
+#[test]
+fn intersects_box() {
+    let f = frustum();
+
+    assert!(f.intersects_box(&BoundingBox::new(v(0.0, 0.0, 0.0), v(2.0, 2.0, 2.0))));
+    // Inside the frustum's bounding box, but beside the apex
+    assert!(!f.intersects_box(&BoundingBox::new(v(3.5, 0.0, -1.5), v(1.0, 1.0, 0.5))));
+    assert!(!f.intersects_box(&BoundingBox::new(v(0.0, 0.0, -12.0), v(1.0, 1.0, 1.0))));
+}
+
+#[test]
+fn intersects_oriented_box() {
+    let f = frustum();
+    let rotation = Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 1.0), f32::consts::FRAC_PI_4);
+
+    assert!(f.intersects_oriented_box(&BoundingOrientedBox::new(v(6.0, 0.0, -5.0), v(1.0, 1.0, 1.0), rotation)));
+    assert!(!f.intersects_oriented_box(&BoundingOrientedBox::new(v(7.5, 0.0, -5.0), v(1.0, 1.0, 1.0), rotation)));
+}
+
+#[test]
+fn intersects_frustum() {
+    let f = frustum();
+    let turned = f.transform(&(Matrix::rotation_y(f32::consts::PI) * Matrix::translation(0.0, 0.0, -15.0)));
+    let far_away = f.transform(&(Matrix::rotation_y(f32::consts::PI) * Matrix::translation(0.0, 0.0, -25.0)));
+
+    assert!(f.intersects_frustum(&f));
+    assert!(f.intersects_frustum(&turned));
+    assert!(!f.intersects_frustum(&far_away));
+}
+
+#[test]
+fn intersects_triangle() {
+    let f = frustum();
+
+    assert!(f.intersects_triangle(&v(-100.0, -100.0, -5.0), &v(100.0, -100.0, -5.0), &v(0.0, 100.0, -5.0)));
+    assert!(!f.intersects_triangle(&v(3.0, 0.0, -2.0), &v(4.0, 0.0, -2.0), &v(3.0, 1.0, -2.0)));
+}
+
+#[test]
+fn intersects_plane() {
+    let f = frustum();
+
+    assert_eq!(f.intersects_plane(&Vector4 { x: 0.0, y: 0.0, z: -1.0, w: -0.5 }), PlaneIntersectionType::Front);
+    assert_eq!(f.intersects_plane(&Vector4 { x: 0.0, y: 0.0, z: -1.0, w: -5.0 }), PlaneIntersectionType::Intersecting);
+    assert_eq!(f.intersects_plane(&Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 10.5 }), PlaneIntersectionType::Front);
+    assert_eq!(f.intersects_plane(&Vector4 { x: 1.0, y: 0.0, z: 0.0, w: -10.5 }), PlaneIntersectionType::Back);
+}
+
+#[test]
+fn intersects_ray() {
+    let f = frustum();
+
+    assert_eq!(f.intersects_ray(&v(0.0, 0.0, -5.0), &v(1.0, 0.0, 0.0)), Some(0.0));
+    assert!(near(f.intersects_ray(&v(0.0, 0.0, 0.0), &v(0.0, 0.0, -1.0)).unwrap(), 1.0));
+    assert!(near(f.intersects_ray(&v(-20.0, 0.0, -5.0), &v(1.0, 0.0, 0.0)).unwrap(), 15.0));
+    assert_eq!(f.intersects_ray(&v(0.0, 0.0, 0.0), &v(0.0, 0.0, 1.0)), None);
+    assert_eq!(f.intersects_ray(&v(-20.0, 0.0, -5.0), &v(-1.0, 0.0, 0.0)), None);
+    assert_eq!(f.intersects_ray(&v(-20.0, 20.0, -5.0), &v(1.0, 0.0, 0.0)), None);
+}
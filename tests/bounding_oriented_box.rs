@@ -0,0 +1,193 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector, Vector3, Vector4, Matrix};
+use xmath::{BoundingSphere, BoundingBox, BoundingOrientedBox, BoundingFrustum, ContainmentType, PlaneIntersectionType};
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn rotation_z(angle: f32) -> Vector4 {
+    Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 1.0), angle)
+}
+
+// Unit cube rotated 45 degrees around z, so it reaches sqrt(2) along x and y
+fn diamond() -> BoundingOrientedBox {
+    BoundingOrientedBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation_z(f32::consts::FRAC_PI_4))
+}
+
+#[test]
+fn new_oriented_box() {
+    let b = BoundingOrientedBox::new(v(1.0, 2.0, 3.0), v(4.0, 5.0, 6.0), Vector4::quaternion_identity());
+
+    assert_eq!(b.center, v(1.0, 2.0, 3.0));
+    assert_eq!(b.extents, v(4.0, 5.0, 6.0));
+    assert_eq!(b.orientation, Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 });
+}
+
+#[test]
+fn create_from_bounding_box() {
+    let b = BoundingOrientedBox::create_from_bounding_box(&BoundingBox::new(v(1.0, 2.0, 3.0), v(4.0, 5.0, 6.0)));
+
+    assert_eq!(b.center, v(1.0, 2.0, 3.0));
+    assert_eq!(b.extents, v(4.0, 5.0, 6.0));
+    assert_eq!(b.orientation, Vector4::quaternion_identity());
+}
+
+#[test]
+fn get_corners() {
+    let aabb = BoundingBox::new(v(1.0, 2.0, 3.0), v(1.0, 2.0, 3.0));
+
+    assert_eq!(BoundingOrientedBox::create_from_bounding_box(&aabb).get_corners(), aabb.get_corners());
+
+    let corners = BoundingOrientedBox::new(v(0.0, 0.0, 1.0), v(2.0, 1.0, 1.0), rotation_z(f32::consts::FRAC_PI_2)).get_corners();
+    assert!((corners[2] - v(-1.0, 2.0, 2.0)).length() < 1e-5);
+    assert!((corners[4] - v(1.0, -2.0, 0.0)).length() < 1e-5);
+}
+
+#[test]
+fn transform() {
+    let b = BoundingOrientedBox::new(v(1.0, 0.0, 0.0), v(1.0, 2.0, 3.0), rotation_z(0.3));
+    let m = Matrix::new(
+        2.0, 0.0, 0.0, 0.0,
+        0.0, 2.0, 0.0, 0.0,
+        0.0, 0.0, 2.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ) * Matrix::rotation_z(0.5) * Matrix::translation(0.0, 0.0, 5.0);
+    let transformed = b.transform(&m);
+
+    assert!((transformed.center - v(2.0 * 0.5f32.cos(), 2.0 * 0.5f32.sin(), 5.0)).length() < 1e-5);
+    assert!((transformed.extents - v(2.0, 4.0, 6.0)).length() < 1e-5);
+    assert!((transformed.orientation - rotation_z(0.8)).length() < 1e-5);
+
+    for (c, expected) in transformed.get_corners().iter().zip(b.get_corners().iter()) {
+        assert!((*c - expected.transform(&m)).length() < 1e-4);
+    }
+}
+
+#[test]
+fn contains_point() {
+    let b = diamond();
+
+    assert_eq!(b.contains_point(&v(1.3, 0.0, 0.0)), ContainmentType::Contains);
+    assert_eq!(b.contains_point(&v(0.9, 0.9, 0.0)), ContainmentType::Disjoint);
+    assert_eq!(b.contains_point(&v(0.0, 0.0, 1.1)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_triangle() {
+    let b = diamond();
+
+    assert_eq!(b.contains_triangle(&v(1.3, 0.0, 0.0), &v(0.0, 1.3, 0.0), &v(0.0, 0.0, 1.0)), ContainmentType::Contains);
+    assert_eq!(b.contains_triangle(&v(1.3, 0.0, 0.0), &v(0.9, 0.9, 0.0), &v(0.0, 0.0, 1.0)), ContainmentType::Intersects);
+    assert_eq!(b.contains_triangle(&v(1.0, 1.0, 0.0), &v(2.0, 1.0, 0.0), &v(1.0, 2.0, 0.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_sphere() {
+    let b = diamond();
+
+    assert_eq!(b.contains_sphere(&BoundingSphere::new(v(0.0, 0.0, 0.0), 1.0)), ContainmentType::Contains);
+    assert_eq!(b.contains_sphere(&BoundingSphere::new(v(1.0, 0.0, 0.0), 0.5)), ContainmentType::Intersects);
+    assert_eq!(b.contains_sphere(&BoundingSphere::new(v(1.2, 1.2, 0.0), 0.5)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_box() {
+    let b = diamond();
+
+    assert_eq!(b.contains_box(&BoundingBox::new(v(0.0, 0.0, 0.0), v(0.7, 0.7, 1.0))), ContainmentType::Contains);
+    assert_eq!(b.contains_box(&BoundingBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0))), ContainmentType::Intersects);
+    assert_eq!(b.contains_box(&BoundingBox::new(v(1.5, 1.5, 0.0), v(0.5, 0.5, 0.5))), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_oriented_box() {
+    let b = diamond();
+    let small = BoundingOrientedBox::new(v(0.0, 0.0, 0.0), v(0.9, 0.9, 0.9), rotation_z(0.8));
+
+    assert_eq!(b.contains_oriented_box(&b), ContainmentType::Contains);
+    assert_eq!(b.contains_oriented_box(&small), ContainmentType::Contains);
+    assert_eq!(b.contains_oriented_box(&BoundingOrientedBox::new(v(2.0, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation_z(0.1))), ContainmentType::Intersects);
+    assert_eq!(b.contains_oriented_box(&BoundingOrientedBox::new(v(3.5, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation_z(0.1))), ContainmentType::Disjoint);
+}
+
+#[test]
+fn intersects_sphere() {
+    let b = diamond();
+
+    assert!(b.intersects_sphere(&BoundingSphere::new(v(1.8, 0.0, 0.0), 0.5)));
+    assert!(!b.intersects_sphere(&BoundingSphere::new(v(1.0, 1.0, 0.0), 0.35)));
+}
+
+#[test]
+fn intersects_box() {
+    let b = diamond();
+
+    assert!(b.intersects_box(&BoundingBox::new(v(1.8, 0.0, 0.0), v(0.5, 0.5, 0.5))));
+    // The bounding boxes overlap but the diamond edge passes by the corner
+    assert!(!b.intersects_box(&BoundingBox::new(v(1.3, 1.3, 0.0), v(0.5, 0.5, 0.5))));
+}
+
+#[test]
+fn intersects_oriented_box() {
+    let b = diamond();
+
+    assert!(b.intersects_oriented_box(&b));
+    assert!(b.intersects_oriented_box(&BoundingOrientedBox::new(v(2.5, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation_z(f32::consts::FRAC_PI_4))));
+    assert!(!b.intersects_oriented_box(&BoundingOrientedBox::new(v(3.0, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation_z(f32::consts::FRAC_PI_4))));
+    assert!(!b.intersects_oriented_box(&BoundingOrientedBox::new(v(0.0, 0.0, 2.5), v(1.0, 1.0, 1.0), Vector4::quaternion_identity())));
+}
+
+#[test]
+fn intersects_oriented_box_on_edge_axis() {
+    // Two boxes whose edges cross skew, separated only by the cross product of those edges
+    let a = BoundingOrientedBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0), Vector4::quaternion_rotation_axis(&v(1.0, 0.0, 0.0), f32::consts::FRAC_PI_4));
+    let b = BoundingOrientedBox::new(v(0.0, 0.0, 2.9), v(1.0, 1.0, 1.0), Vector4::quaternion_rotation_axis(&v(0.0, 1.0, 0.0), f32::consts::FRAC_PI_4));
+    let c = BoundingOrientedBox::new(v(0.0, 0.0, 2.7), v(1.0, 1.0, 1.0), Vector4::quaternion_rotation_axis(&v(0.0, 1.0, 0.0), f32::consts::FRAC_PI_4));
+
+    assert!(!a.intersects_oriented_box(&b));
+    assert!(a.intersects_oriented_box(&c));
+}
+
+#[test]
+fn intersects_triangle() {
+    let b = diamond();
+
+    assert!(b.intersects_triangle(&v(1.3, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(3.0, 1.0, 0.0)));
+    assert!(!b.intersects_triangle(&v(1.0, 1.0, 0.0), &v(2.0, 1.0, 0.0), &v(1.0, 2.0, 0.0)));
+}
+
+#[test]
+fn intersects_plane() {
+    let b = diamond();
+    let s = 0.5f32.sqrt();
+    let plane = |d: f32| Vector4 { x: 1.0, y: 0.0, z: 0.0, w: d };
+
+    assert_eq!(b.intersects_plane(&plane(1.5)), PlaneIntersectionType::Front);
+    assert_eq!(b.intersects_plane(&plane(-1.4)), PlaneIntersectionType::Intersecting);
+    assert_eq!(b.intersects_plane(&plane(-1.5)), PlaneIntersectionType::Back);
+    assert_eq!(b.intersects_plane(&Vector4 { x: s, y: s, z: 0.0, w: -1.1 }), PlaneIntersectionType::Back);
+}
+
+#[test]
+fn intersects_ray() {
+    let b = diamond();
+    let s = f32::consts::SQRT_2;
+
+    let dist = b.intersects_ray(&v(-5.0, 0.0, 0.0), &v(1.0, 0.0, 0.0)).unwrap();
+    assert!((dist - (5.0 - s)).abs() < 1e-5);
+    let dist = b.intersects_ray(&v(0.0, 0.0, 0.0), &v(1.0, 0.0, 0.0)).unwrap();
+    assert!((dist + s).abs() < 1e-5);
+    assert_eq!(b.intersects_ray(&v(-5.0, 0.0, 0.0), &v(-1.0, 0.0, 0.0)), None);
+    assert_eq!(b.intersects_ray(&v(-5.0, 1.0, 0.0), &v(0.0, 0.0, 1.0)), None);
+}
+
+#[test]
+fn intersects_frustum() {
+    let f = BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0));
+
+    assert!(BoundingOrientedBox::new(v(0.0, 0.0, -1.0), v(1.0, 1.0, 1.0), rotation_z(0.3)).intersects_frustum(&f));
+    assert!(!BoundingOrientedBox::new(v(0.0, 0.0, -1.0), v(1.0, 1.0, 1.0), rotation_z(0.3)).intersects_frustum(&f.transform(&Matrix::translation(0.0, 0.0, 10.0))));
+}
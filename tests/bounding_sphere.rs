@@ -0,0 +1,228 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector3, Vector4, Matrix};
+use xmath::{BoundingSphere, BoundingBox, BoundingOrientedBox, BoundingFrustum, ContainmentType, PlaneIntersectionType};
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn unit_sphere() -> BoundingSphere {
+    BoundingSphere::new(v(0.0, 0.0, 0.0), 1.0)
+}
+
+#[test]
+fn new_sphere() {
+    let s = BoundingSphere::new(v(1.0, 2.0, 3.0), 4.0);
+
+    assert_eq!(s.center, v(1.0, 2.0, 3.0));
+    assert_eq!(s.radius, 4.0);
+}
+
+#[test]
+#[should_panic]
+fn new_sphere_should_fail_when_radius_is_negative() {
+    let _ = BoundingSphere::new(v(0.0, 0.0, 0.0), -1.0);
+}
+
+#[test]
+fn create_from_bounding_box() {
+    let b = BoundingBox::new(v(1.0, 1.0, 1.0), v(1.0, 2.0, 2.0));
+    let s = BoundingSphere::create_from_bounding_box(&b);
+
+    assert_eq!(s.center, v(1.0, 1.0, 1.0));
+    assert_eq!(s.radius, 3.0);
+}
+
+#[test]
+fn create_merged() {
+    let s1 = BoundingSphere::new(v(-2.0, 0.0, 0.0), 1.0);
+    let s2 = BoundingSphere::new(v(2.0, 0.0, 0.0), 2.0);
+    let merged = BoundingSphere::create_merged(&s1, &s2);
+
+    assert_eq!(merged.center, v(0.5, 0.0, 0.0));
+    assert_eq!(merged.radius, 3.5);
+}
+
+#[test]
+fn create_merged_when_one_contains_the_other() {
+    let s1 = BoundingSphere::new(v(0.0, 0.0, 0.0), 5.0);
+    let s2 = BoundingSphere::new(v(1.0, 1.0, 0.0), 1.0);
+
+    assert_eq!(BoundingSphere::create_merged(&s1, &s2), s1);
+    assert_eq!(BoundingSphere::create_merged(&s2, &s1), s1);
+}
+
+#[test]
+fn create_from_points() {
+    let points = [
+        v(1.0, 0.0, 0.0),
+        v(-1.0, 0.0, 0.0),
+        v(0.0, 0.5, 0.0),
+        v(0.0, 0.0, 0.9),
+        v(0.3, -0.3, -0.3),
+    ];
+    let s = BoundingSphere::create_from_points(&points);
+
+    assert_eq!(s.center, v(0.0, 0.0, 0.0));
+    assert_eq!(s.radius, 1.0);
+}
+
+#[test]
+fn create_from_points_grows_to_contain_every_point() {
+    let points = [
+        v(0.0, 0.0, 0.0),
+        v(4.0, 0.0, 0.0),
+        v(2.0, 3.0, 0.0),
+        v(2.0, -1.0, 3.0),
+        v(-1.0, 2.0, -2.0),
+    ];
+    let s = BoundingSphere::create_from_points(&points);
+
+    for p in points.iter() {
+        assert!((*p - s.center).length() <= s.radius * 1.0001);
+    }
+}
+
+#[test]
+fn transform() {
+    let s = BoundingSphere::new(v(1.0, 0.0, 0.0), 2.0);
+    let m = Matrix::new(
+        2.0, 0.0, 0.0, 0.0,
+        0.0, 3.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        0.0, 0.0, 5.0, 1.0,
+    );
+    let transformed = s.transform(&m);
+
+    assert_eq!(transformed.center, v(2.0, 0.0, 5.0));
+    assert_eq!(transformed.radius, 6.0);
+}
+
+#[test]
+fn transform_by_rotation_keeps_radius() {
+    let s = BoundingSphere::new(v(1.0, 0.0, 0.0), 2.0);
+    let transformed = s.transform(&(Matrix::rotation_z(0.9) * Matrix::rotation_x(0.3)));
+
+    assert!((transformed.radius - 2.0).abs() < 1e-6);
+    assert!((transformed.center.length() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn contains_point() {
+    let s = unit_sphere();
+
+    assert_eq!(s.contains_point(&v(0.5, 0.5, 0.5)), ContainmentType::Contains);
+    assert_eq!(s.contains_point(&v(1.0, 0.0, 0.0)), ContainmentType::Contains);
+    assert_eq!(s.contains_point(&v(0.8, 0.8, 0.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_triangle() {
+    let s = unit_sphere();
+
+    assert_eq!(s.contains_triangle(&v(0.1, 0.0, 0.0), &v(0.0, 0.5, 0.0), &v(0.0, 0.0, 0.5)), ContainmentType::Contains);
+    assert_eq!(s.contains_triangle(&v(0.0, 0.0, 0.0), &v(5.0, 0.0, 0.0), &v(0.0, 5.0, 0.0)), ContainmentType::Intersects);
+    assert_eq!(s.contains_triangle(&v(-5.0, -5.0, 0.5), &v(5.0, -5.0, 0.5), &v(0.0, 5.0, 0.5)), ContainmentType::Intersects);
+    assert_eq!(s.contains_triangle(&v(2.0, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(2.0, 1.0, 0.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_sphere() {
+    let s = BoundingSphere::new(v(0.0, 0.0, 0.0), 3.0);
+
+    assert_eq!(s.contains_sphere(&BoundingSphere::new(v(1.0, 0.0, 0.0), 1.0)), ContainmentType::Contains);
+    assert_eq!(s.contains_sphere(&BoundingSphere::new(v(3.0, 0.0, 0.0), 1.0)), ContainmentType::Intersects);
+    assert_eq!(s.contains_sphere(&BoundingSphere::new(v(0.0, 0.0, 0.0), 4.0)), ContainmentType::Intersects);
+    assert_eq!(s.contains_sphere(&BoundingSphere::new(v(5.0, 0.0, 0.0), 1.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contains_box() {
+    let s = BoundingSphere::new(v(0.0, 0.0, 0.0), 2.0);
+
+    assert_eq!(s.contains_box(&BoundingBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0))), ContainmentType::Contains);
+    assert_eq!(s.contains_box(&BoundingBox::new(v(2.0, 0.0, 0.0), v(1.0, 1.0, 1.0))), ContainmentType::Intersects);
+    assert_eq!(s.contains_box(&BoundingBox::new(v(3.0, 3.0, 0.0), v(1.0, 1.0, 1.0))), ContainmentType::Disjoint);
+}
+
+#[test]
+fn intersects_sphere() {
+    let s = unit_sphere();
+
+    assert!(s.intersects_sphere(&BoundingSphere::new(v(1.5, 0.0, 0.0), 1.0)));
+    assert!(s.intersects_sphere(&BoundingSphere::new(v(2.0, 0.0, 0.0), 1.0)));
+    assert!(!s.intersects_sphere(&BoundingSphere::new(v(2.0, 0.1, 0.0), 1.0)));
+}
+
+#[test]
+fn intersects_box() {
+    let s = unit_sphere();
+
+    assert!(s.intersects_box(&BoundingBox::new(v(1.5, 0.0, 0.0), v(0.5, 0.5, 0.5))));
+    assert!(s.intersects_box(&BoundingBox::new(v(0.0, 0.0, 0.0), v(5.0, 5.0, 5.0))));
+    assert!(!s.intersects_box(&BoundingBox::new(v(1.5, 1.5, 0.0), v(0.5, 0.5, 0.5))));
+}
+
+#[test]
+fn intersects_triangle() {
+    let s = unit_sphere();
+
+    // Through the face
+    assert!(s.intersects_triangle(&v(-5.0, -5.0, 0.5), &v(5.0, -5.0, 0.5), &v(0.0, 5.0, 0.5)));
+    // Through an edge only
+    assert!(s.intersects_triangle(&v(0.5, -5.0, 0.0), &v(0.5, 5.0, 0.0), &v(5.0, 0.0, 0.0)));
+    // Close to the plane, outside the triangle
+    assert!(!s.intersects_triangle(&v(2.0, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(2.0, 1.0, 0.0)));
+    // Far from the plane
+    assert!(!s.intersects_triangle(&v(-5.0, -5.0, 2.0), &v(5.0, -5.0, 2.0), &v(0.0, 5.0, 2.0)));
+}
+
+#[test]
+fn intersects_degenerate_triangle() {
+    let s = unit_sphere();
+
+    assert!(s.intersects_triangle(&v(-2.0, 0.5, 0.0), &v(2.0, 0.5, 0.0), &v(0.0, 0.5, 0.0)));
+    assert!(!s.intersects_triangle(&v(-2.0, 2.0, 0.0), &v(2.0, 2.0, 0.0), &v(0.0, 2.0, 0.0)));
+    assert!(!s.intersects_triangle(&v(3.0, 0.0, 0.0), &v(3.0, 0.0, 0.0), &v(3.0, 0.0, 0.0)));
+}
+
+#[test]
+fn intersects_plane() {
+    let s = BoundingSphere::new(v(0.0, 2.0, 0.0), 1.0);
+    let plane = |d: f32| Vector4 { x: 0.0, y: 1.0, z: 0.0, w: d };
+
+    assert_eq!(s.intersects_plane(&plane(0.0)), PlaneIntersectionType::Front);
+    assert_eq!(s.intersects_plane(&plane(-2.5)), PlaneIntersectionType::Intersecting);
+    assert_eq!(s.intersects_plane(&plane(-4.0)), PlaneIntersectionType::Back);
+}
+
+#[test]
+fn intersects_ray() {
+    let s = BoundingSphere::new(v(0.0, 0.0, 5.0), 1.0);
+    let origin = v(0.0, 0.0, 0.0);
+
+    assert_eq!(s.intersects_ray(&origin, &v(0.0, 0.0, 1.0)), Some(4.0));
+    assert_eq!(s.intersects_ray(&origin, &v(0.0, 0.0, -1.0)), None);
+    assert_eq!(s.intersects_ray(&origin, &v(0.0, 1.0, 0.0)), None);
+    assert_eq!(s.intersects_ray(&v(0.0, 0.0, 5.0), &v(1.0, 0.0, 0.0)), Some(1.0));
+    assert_eq!(s.intersects_ray(&v(0.0, 1.0, 0.0), &v(0.0, 0.0, 1.0)), Some(5.0));
+}
+
+#[test]
+fn intersects_oriented_box() {
+    let s = unit_sphere();
+    let rotation = Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 1.0), f32::consts::FRAC_PI_4);
+
+    assert!(s.intersects_oriented_box(&BoundingOrientedBox::new(v(2.3, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation)));
+    assert!(!s.intersects_oriented_box(&BoundingOrientedBox::new(v(2.5, 0.0, 0.0), v(1.0, 1.0, 1.0), rotation)));
+}
+
+#[test]
+fn intersects_frustum() {
+    let f = BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 2.0));
+
+    assert!(BoundingSphere::new(v(0.0, 0.0, -1.0), 1.0).intersects_frustum(&f));
+    assert!(!BoundingSphere::new(v(0.0, 0.0, -1.0), 1.0).intersects_frustum(&f.transform(&Matrix::translation(0.0, 0.0, 10.0))));
+}
@@ -0,0 +1,241 @@
+extern crate glium;
+extern crate xmath;
+
+use std::f32;
+use glium::uniforms::{AsUniformValue, UniformValue};
+use xmath::{Vector4, Color};
+use xmath::packed::UByteN4;
+
+fn assert_near(v1: &Vector4, v2: &Vector4, epsilon: f32) {
+    assert!((v1.x - v2.x).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.y - v2.y).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.z - v2.z).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.w - v2.w).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+}
+
+fn colors() -> Vec<Vector4> {
+    let mut colors = Vec::new();
+    for r in 0..5 {
+        for g in 0..5 {
+            for b in 0..5 {
+                colors.push(Vector4 { x: r as f32 / 4.0, y: g as f32 / 4.0, z: b as f32 / 4.0, w: 0.3 });
+            }
+        }
+    }
+    colors.push(Vector4 { x: 0.9, y: 0.1, z: 0.35, w: 1.0 });
+    colors
+}
+
+#[test]
+fn srgb_to_rgb() {
+    let v = Vector4 { x: 0.0, y: 0.5, z: 1.0, w: 0.7 };
+    let linear = v.srgb_to_rgb();
+
+    assert_near(&linear, &Vector4 { x: 0.0, y: 0.214041, z: 1.0, w: 0.7 }, 1e-6);
+    assert_eq!(Vector4 { x: 0.04, y: 0.0, z: 0.0, w: 0.0 }.srgb_to_rgb().x, 0.04 / 12.92);
+}
+
+#[test]
+fn rgb_to_srgb() {
+    let v = Vector4 { x: 0.0, y: 0.214041, z: 1.0, w: 0.7 };
+
+    assert_near(&v.rgb_to_srgb(), &Vector4 { x: 0.0, y: 0.5, z: 1.0, w: 0.7 }, 1e-5);
+    assert_near(&Vector4 { x: -1.0, y: 2.0, z: 0.001, w: 5.0 }.rgb_to_srgb(), &Vector4 { x: 0.0, y: 1.0, z: 0.01292, w: 5.0 }, 1e-6);
+}
+
+#[test]
+fn srgb_round_trip() {
+    for c in colors().iter() {
+        assert_near(&c.srgb_to_rgb().rgb_to_srgb(), c, 1e-5);
+    }
+}
+
+#[test]
+fn rgb_to_hsl() {
+    assert_near(&Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 }.rgb_to_hsl(), &Vector4 { x: 0.0, y: 1.0, z: 0.5, w: 0.5 }, 1e-6);
+    assert_near(&Vector4 { x: 0.0, y: 0.5, z: 0.0, w: 0.5 }.rgb_to_hsl(), &Vector4 { x: 1.0 / 3.0, y: 1.0, z: 0.25, w: 0.5 }, 1e-6);
+    assert_near(&Vector4 { x: 0.5, y: 0.5, z: 1.0, w: 0.5 }.rgb_to_hsl(), &Vector4 { x: 2.0 / 3.0, y: 1.0, z: 0.75, w: 0.5 }, 1e-6);
+    assert_near(&Vector4 { x: 0.6, y: 0.6, z: 0.6, w: 0.5 }.rgb_to_hsl(), &Vector4 { x: 0.0, y: 0.0, z: 0.6, w: 0.5 }, 1e-6);
+}
+
+#[test]
+fn hsl_round_trip() {
+    for c in colors().iter() {
+        assert_near(&c.rgb_to_hsl().hsl_to_rgb(), c, 1e-5);
+    }
+}
+
+#[test]
+fn rgb_to_hsv() {
+    assert_near(&Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 }.rgb_to_hsv(), &Vector4 { x: 0.0, y: 1.0, z: 1.0, w: 0.5 }, 1e-6);
+    assert_near(&Vector4 { x: 0.0, y: 0.5, z: 0.5, w: 0.5 }.rgb_to_hsv(), &Vector4 { x: 0.5, y: 1.0, z: 0.5, w: 0.5 }, 1e-6);
+    assert_near(&Vector4 { x: 1.0, y: 0.5, z: 1.0, w: 0.5 }.rgb_to_hsv(), &Vector4 { x: 5.0 / 6.0, y: 0.5, z: 1.0, w: 0.5 }, 1e-6);
+    assert_near(&Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.5 }.rgb_to_hsv(), &Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.5 }, 1e-6);
+}
+
+#[test]
+fn hsv_round_trip() {
+    for c in colors().iter() {
+        assert_near(&c.rgb_to_hsv().hsv_to_rgb(), c, 1e-5);
+    }
+}
+
+#[test]
+fn hsv_to_rgb_wraps_hue() {
+    let v = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 0.5 };
+
+    assert_near(&v.hsv_to_rgb(), &Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 }, 1e-6);
+}
+
+#[test]
+fn rgb_to_yuv() {
+    let white = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 0.5 };
+
+    assert_near(&white.rgb_to_yuv(), &Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 }, 1e-6);
+    assert_near(&white.rgb_to_yuv_hd(), &Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 }, 1e-6);
+    assert_near(&white.rgb_to_yuv_uhd(), &Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 }, 1e-6);
+
+    let red = Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.5 };
+    assert_eq!(red.rgb_to_yuv().x, 0.299);
+    assert_eq!(red.rgb_to_yuv_hd().x, 0.2126);
+    assert_eq!(red.rgb_to_yuv_uhd().x, 0.2627);
+}
+
+#[test]
+fn yuv_round_trip() {
+    for c in colors().iter() {
+        assert_near(&c.rgb_to_yuv().yuv_to_rgb(), c, 2e-3);
+        assert_near(&c.rgb_to_yuv_hd().yuv_hd_to_rgb(), c, 2e-3);
+        assert_near(&c.rgb_to_yuv_uhd().yuv_uhd_to_rgb(), c, 2e-3);
+    }
+}
+
+#[test]
+fn rgb_to_xyz() {
+    let white = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 0.5 };
+    let xyz = white.rgb_to_xyz();
+
+    assert!((xyz.y - 1.0 / 0.17697).abs() < 1e-4);
+    assert_eq!(xyz.w, 0.5);
+}
+
+#[test]
+fn xyz_round_trip() {
+    for c in colors().iter() {
+        assert_near(&c.rgb_to_xyz().xyz_to_rgb(), c, 1e-4);
+    }
+}
+
+#[test]
+fn srgb_to_xyz() {
+    let white = Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 0.5 };
+
+    // D65 white point
+    assert_near(&white.srgb_to_xyz(), &Vector4 { x: 0.9505, y: 1.0, z: 1.089, w: 0.5 }, 1e-4);
+}
+
+#[test]
+fn srgb_xyz_round_trip() {
+    for c in colors().iter() {
+        assert_near(&c.srgb_to_xyz().xyz_to_srgb(), c, 1e-3);
+    }
+}
+
+#[test]
+fn new_color() {
+    let c = Color::new(0.1, 0.2, 0.3, 0.4);
+
+    assert_eq!(c.0, Vector4 { x: 0.1, y: 0.2, z: 0.3, w: 0.4 });
+    assert_eq!((c.r(), c.g(), c.b(), c.a()), (0.1, 0.2, 0.3, 0.4));
+}
+
+#[test]
+fn adjust_saturation() {
+    let c = Color::new(1.0, 0.0, 0.0, 0.5);
+
+    assert_eq!(c.adjust_saturation(1.0), c);
+    assert_near(&c.adjust_saturation(0.0).0, &Vector4 { x: 0.2125, y: 0.2125, z: 0.2125, w: 0.5 }, 1e-6);
+    assert_near(&c.adjust_saturation(2.0).0, &Vector4 { x: 1.7875, y: -0.2125, z: -0.2125, w: 0.5 }, 1e-6);
+}
+
+#[test]
+fn adjust_contrast() {
+    let c = Color::new(0.75, 0.5, 0.0, 0.2);
+
+    assert_eq!(c.adjust_contrast(1.0), c);
+    assert_eq!(c.adjust_contrast(0.0), Color::new(0.5, 0.5, 0.5, 0.2));
+    assert_eq!(c.adjust_contrast(2.0), Color::new(1.0, 0.5, -0.5, 0.2));
+}
+
+#[test]
+fn negative() {
+    assert_eq!(Color::new(0.25, 1.0, 0.0, 0.3).negative(), Color::new(0.75, 0.0, 1.0, 0.3));
+}
+
+#[test]
+fn modulate() {
+    let c1 = Color::new(0.5, 1.0, 0.25, 0.5);
+    let c2 = Color::new(0.5, 0.5, 4.0, 0.5);
+
+    assert_eq!(c1.modulate(&c2), Color::new(0.25, 0.5, 1.0, 0.25));
+}
+
+#[test]
+fn premultiply_alpha() {
+    let c = Color::new(1.0, 0.5, 0.25, 0.5);
+    let premultiplied = c.premultiply_alpha();
+
+    assert_eq!(premultiplied, Color::new(0.5, 0.25, 0.125, 0.5));
+    assert_eq!(premultiplied.unpremultiply_alpha(), c);
+    assert_eq!(Color::new(1.0, 1.0, 1.0, 0.0).unpremultiply_alpha(), Color::new(0.0, 0.0, 0.0, 0.0));
+}
+
+#[test]
+fn comparisons() {
+    let c1 = Color::new(0.1, 0.2, 0.3, 0.4);
+    let c2 = Color::new(0.2, 0.3, 0.4, 0.5);
+    let c3 = Color::new(0.1, 0.3, 0.4, 0.5);
+
+    assert!(c1.equal(&c1));
+    assert!(c1.not_equal(&c2));
+    assert!(c1.less(&c2));
+    assert!(!c1.less(&c3));
+    assert!(c1.less_or_equal(&c3));
+    assert!(c2.greater(&c1));
+    assert!(!c3.greater(&c1));
+    assert!(c3.greater_or_equal(&c1));
+}
+
+#[test]
+fn is_nan_and_is_infinite() {
+    assert!(!Color::new(0.1, 0.2, 0.3, 0.4).is_nan());
+    assert!(Color::new(0.1, 0.2, f32::NAN, 0.4).is_nan());
+    assert!(!Color::new(0.1, 0.2, 0.3, 0.4).is_infinite());
+    assert!(Color::new(0.1, 0.2, 0.3, f32::NEG_INFINITY).is_infinite());
+}
+
+#[test]
+fn bgra8_conversion() {
+    let c = Color::new(1.0, 0.0, 0.2, 1.0);
+    let packed = c.to_bgra8();
+
+    assert_eq!(packed.c, 0xFFFF0033);
+    assert_eq!(Color::from_bgra8(&packed), c);
+}
+
+#[test]
+fn rgba8_conversion() {
+    let c = Color::new(1.0, 0.0, 0.2, 1.0);
+    let packed = c.to_rgba8();
+
+    assert_eq!(packed, UByteN4 { x: 255, y: 0, z: 51, w: 255 });
+    assert_eq!(Color::from_rgba8(&packed), c);
+}
+
+#[test]
+fn color_as_uniform_value() {
+    match Color::new(0.1, 0.2, 0.3, 0.4).as_uniform_value() {
+        UniformValue::Vec4(v) => assert_eq!(v, [0.1, 0.2, 0.3, 0.4]),
+        _ => panic!("colour should be a vec4 uniform"),
+    }
+}
@@ -0,0 +1,69 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector3, Vector4, Matrix};
+use xmath::{BoundingSphere, BoundingBox, BoundingOrientedBox, BoundingFrustum, ContainmentType};
+
+// Every volume decides contains_oriented_box and contains_frustum from the corners of the other
+// shape, falling back to its intersection test, so they are all checked with the same shapes.
+// Each volume below surrounds (0, 0, -1) with room for a radius of about 2.2.
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn oriented_boxes() -> Vec<(BoundingOrientedBox, ContainmentType)> {
+    let rotation = Vector4::quaternion_rotation_axis(&v(1.0, 1.0, 0.0), 0.6);
+    vec![
+        (BoundingOrientedBox::new(v(0.0, 0.0, -1.0), v(0.5, 0.5, 0.5), rotation), ContainmentType::Contains),
+        (BoundingOrientedBox::new(v(0.0, 0.0, -1.0), v(10.0, 0.1, 0.1), rotation), ContainmentType::Intersects),
+        (BoundingOrientedBox::new(v(20.0, 0.0, -1.0), v(0.5, 0.5, 0.5), rotation), ContainmentType::Disjoint),
+    ]
+}
+
+// Corners up to (1.5, 1.5, -1.5)
+fn frusta() -> Vec<(BoundingFrustum, ContainmentType)> {
+    let f = BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 0.5, 1.5));
+    vec![
+        (f, ContainmentType::Contains),
+        (BoundingFrustum { far: 50.0, ..f }, ContainmentType::Intersects),
+        (f.transform(&Matrix::translation(20.0, 0.0, 0.0)), ContainmentType::Disjoint),
+    ]
+}
+
+fn check<F, G>(contains_oriented_box: F, contains_frustum: G)
+    where F: Fn(&BoundingOrientedBox) -> ContainmentType, G: Fn(&BoundingFrustum) -> ContainmentType
+{
+    for &(ref b, expected) in oriented_boxes().iter() {
+        assert_eq!(contains_oriented_box(b), expected, "{:?}", b);
+    }
+    for &(ref f, expected) in frusta().iter() {
+        assert_eq!(contains_frustum(f), expected, "{:?}", f);
+    }
+}
+
+#[test]
+fn sphere() {
+    let s = BoundingSphere::new(v(0.0, 0.0, -1.0), 2.5);
+    check(|b| s.contains_oriented_box(b), |f| s.contains_frustum(f));
+}
+
+#[test]
+fn axis_aligned_box() {
+    let b = BoundingBox::new(v(0.0, 0.0, -1.0), v(2.0, 2.0, 2.0));
+    check(|o| b.contains_oriented_box(o), |f| b.contains_frustum(f));
+}
+
+#[test]
+fn oriented_box() {
+    let rotation = Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 1.0), 0.3);
+    let b = BoundingOrientedBox::new(v(0.0, 0.0, -1.0), v(2.0, 2.0, 2.0), rotation);
+    check(|o| b.contains_oriented_box(o), |f| b.contains_frustum(f));
+}
+
+#[test]
+fn frustum() {
+    let projection = Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0);
+    let fr = BoundingFrustum::create_from_matrix(&projection).transform(&Matrix::translation(0.0, 0.0, 4.0));
+    check(|o| fr.contains_oriented_box(o), |f| fr.contains_frustum(f));
+}
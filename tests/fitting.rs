@@ -0,0 +1,139 @@
+extern crate xmath;
+
+use xmath::{Vector, Vector3, Vector4, Matrix};
+use xmath::{BoundingSphere, BoundingBox, BoundingOrientedBox, ContainmentType};
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+// Deterministic xorshift generator, uniform in [-1, 1)
+struct Random(u32);
+
+impl Random {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1 << 23) as f32 - 1.0
+    }
+
+    fn vector(&mut self) -> Vector3 {
+        v(self.next(), self.next(), self.next())
+    }
+}
+
+// Point clouds of different shapes: a cube, a stretched and rotated slab, a sphere shell and a
+// few points far from the origin, each scaled and moved by an amount that depends on the seed
+fn clouds(seed: u32) -> Vec<Vec<Vector3>> {
+    let mut random = Random(seed);
+    let stretch = Matrix::new(
+        10.0, 0.0, 0.0, 0.0,
+        0.0, 2.0, 0.0, 0.0,
+        0.0, 0.0, 0.2, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ) * Matrix::rotation_x(0.6) * Matrix::rotation_z(-1.1) * Matrix::translation(3.0, -4.0, 5.0);
+    let scale = 10.0f32.powf(random.next() * 3.0);
+    let offset = random.vector() * 1000.0;
+
+    let clouds: Vec<Vec<Vector3>> = vec![
+        (0..500).map(|_| random.vector()).collect(),
+        (0..500).map(|_| random.vector().transform(&stretch)).collect(),
+        (0..500).map(|_| random.vector().normalize() * 7.0).collect(),
+        (0..5).map(|_| random.vector() * 1000.0 + v(5000.0, 0.0, -300.0)).collect(),
+    ];
+    clouds.into_iter().map(|c| c.into_iter().map(|p| p * scale + offset).collect()).collect()
+}
+
+// Enough seeds to catch points left outside by rounding, which only happens for some clouds
+fn all_clouds() -> Vec<Vec<Vector3>> {
+    (1..=300).flat_map(clouds).collect()
+}
+
+#[test]
+fn sphere_contains_all_points() {
+    for points in all_clouds().iter() {
+        let s = BoundingSphere::create_from_points(points);
+
+        for p in points.iter() {
+            assert_eq!(s.contains_point(p), ContainmentType::Contains);
+        }
+    }
+}
+
+#[test]
+fn box_contains_all_points() {
+    for points in all_clouds().iter() {
+        let b = BoundingBox::create_from_points(points);
+
+        for p in points.iter() {
+            assert_eq!(b.contains_point(p), ContainmentType::Contains);
+        }
+    }
+}
+
+#[test]
+fn box_is_exact() {
+    for points in all_clouds().iter() {
+        let b = BoundingBox::create_from_points(points);
+        let (min, max) = (b.min(), b.max());
+
+        for i in 0..3 {
+            assert!(points.iter().any(|p| (p[i] - min[i]).abs() <= 1e-3 * max[i].abs().max(1.0)));
+            assert!(points.iter().any(|p| (p[i] - max[i]).abs() <= 1e-3 * max[i].abs().max(1.0)));
+        }
+    }
+}
+
+#[test]
+fn oriented_box_contains_all_points() {
+    for points in all_clouds().iter() {
+        let b = BoundingOrientedBox::create_from_points(points);
+
+        assert!((b.orientation.length() - 1.0).abs() < 1e-5);
+        for p in points.iter() {
+            assert_eq!(b.contains_point(p), ContainmentType::Contains);
+        }
+    }
+}
+
+#[test]
+fn oriented_box_fits_rotated_box() {
+    let mut random = Random(42);
+    let orientation = Vector4::quaternion_rotation_axis(&v(1.0, 2.0, 3.0), 0.9);
+    let extents = v(8.0, 3.0, 0.5);
+    let points: Vec<_> = (0..2000).map(|_| (random.vector() * extents).rotate(&orientation) + v(1.0, 2.0, 3.0)).collect();
+
+    let b = BoundingOrientedBox::create_from_points(&points);
+    let volume = |e: &Vector3| e.x * e.y * e.z;
+
+    assert!((b.center - v(1.0, 2.0, 3.0)).length() < 0.1);
+    assert!(volume(&b.extents) < volume(&extents) * 1.1);
+    assert!(volume(&b.extents) < volume(&BoundingBox::create_from_points(&points).extents) * 0.5);
+}
+
+#[test]
+fn oriented_box_of_single_point() {
+    let b = BoundingOrientedBox::create_from_points(&[v(1.0, 2.0, 3.0)]);
+
+    assert_eq!(b.extents, Vector3::zero());
+    assert!((b.center - v(1.0, 2.0, 3.0)).length() < 1e-6);
+    assert_eq!(b.contains_point(&v(1.0, 2.0, 3.0)), ContainmentType::Contains);
+}
+
+#[test]
+fn oriented_box_of_collinear_points() {
+    let points = [v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0), v(2.0, 2.0, 2.0), v(-1.0, -1.0, -1.0)];
+    let b = BoundingOrientedBox::create_from_points(&points);
+
+    for p in points.iter() {
+        assert_eq!(b.contains_point(p), ContainmentType::Contains);
+    }
+    assert!(b.extents.y.abs() < 1e-5 || b.extents.x.abs() < 1e-5 || b.extents.z.abs() < 1e-5);
+}
+
+#[test]
+#[should_panic]
+fn oriented_box_from_no_points() {
+    let _ = BoundingOrientedBox::create_from_points(&[]);
+}
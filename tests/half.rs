@@ -0,0 +1,147 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector2, Vector4, Half2, Half4};
+use xmath::{convert_half_to_float, convert_float_to_half};
+use xmath::{convert_half_to_float_stream, convert_float_to_half_stream};
+
+#[test]
+fn half_to_float() {
+    assert_eq!(convert_half_to_float(0x0000), 0.0);
+    assert_eq!(convert_half_to_float(0x3C00), 1.0);
+    assert_eq!(convert_half_to_float(0xC000), -2.0);
+    assert_eq!(convert_half_to_float(0x3555), 1365.0 / 4096.0);
+    assert_eq!(convert_half_to_float(0x7BFF), 65504.0);
+    assert_eq!(convert_half_to_float(0x0400), 2.0f32.powi(-14));
+}
+
+#[test]
+fn half_to_float_negative_zero() {
+    let v = convert_half_to_float(0x8000);
+
+    assert_eq!(v, 0.0);
+    assert!(v.is_sign_negative());
+}
+
+#[test]
+fn half_to_float_denormal() {
+    assert_eq!(convert_half_to_float(0x0001), 2.0f32.powi(-24));
+    assert_eq!(convert_half_to_float(0x03FF), 1023.0 * 2.0f32.powi(-24));
+    assert_eq!(convert_half_to_float(0x8200), -512.0 * 2.0f32.powi(-24));
+}
+
+#[test]
+fn half_to_float_infinity_and_nan() {
+    assert_eq!(convert_half_to_float(0x7C00), f32::INFINITY);
+    assert_eq!(convert_half_to_float(0xFC00), f32::NEG_INFINITY);
+    assert!(convert_half_to_float(0x7E00).is_nan());
+    assert!(convert_half_to_float(0xFC01).is_nan());
+}
+
+#[test]
+fn float_to_half() {
+    assert_eq!(convert_float_to_half(0.0), 0x0000);
+    assert_eq!(convert_float_to_half(-0.0), 0x8000);
+    assert_eq!(convert_float_to_half(1.0), 0x3C00);
+    assert_eq!(convert_float_to_half(-2.0), 0xC000);
+    assert_eq!(convert_float_to_half(65504.0), 0x7BFF);
+}
+
+#[test]
+fn float_to_half_rounds_to_nearest_even() {
+    let ulp = 2.0f32.powi(-10);
+
+    assert_eq!(convert_float_to_half(1.0 + ulp * 0.5), 0x3C00);
+    assert_eq!(convert_float_to_half(1.0 + ulp * 1.5), 0x3C02);
+    assert_eq!(convert_float_to_half(1.0 + ulp * 0.51), 0x3C01);
+    assert_eq!(convert_float_to_half(1.0 + ulp * 0.49), 0x3C00);
+}
+
+#[test]
+fn float_to_half_denormal() {
+    assert_eq!(convert_float_to_half(2.0f32.powi(-24)), 0x0001);
+    assert_eq!(convert_float_to_half(-2.0f32.powi(-24)), 0x8001);
+    assert_eq!(convert_float_to_half(2.0f32.powi(-25)), 0x0000);
+    assert_eq!(convert_float_to_half(2.0f32.powi(-25) * 1.01), 0x0001);
+    assert_eq!(convert_float_to_half(2.5 * 2.0f32.powi(-24)), 0x0002);
+    assert_eq!(convert_float_to_half(3.5 * 2.0f32.powi(-24)), 0x0004);
+    assert_eq!(convert_float_to_half(1.0e-10), 0x0000);
+}
+
+#[test]
+fn float_to_half_overflow() {
+    assert_eq!(convert_float_to_half(65519.0), 0x7BFF);
+    assert_eq!(convert_float_to_half(65520.0), 0x7C00);
+    assert_eq!(convert_float_to_half(1.0e10), 0x7C00);
+    assert_eq!(convert_float_to_half(-1.0e10), 0xFC00);
+    assert_eq!(convert_float_to_half(f32::INFINITY), 0x7C00);
+    assert_eq!(convert_float_to_half(f32::NEG_INFINITY), 0xFC00);
+}
+
+#[test]
+fn float_to_half_nan() {
+    let h = convert_float_to_half(f32::NAN);
+
+    assert_eq!(h & 0x7C00, 0x7C00);
+    assert!(h & 0x03FF != 0);
+}
+
+#[test]
+fn every_half_survives_round_trip() {
+    for h in 0..0x10000u32 {
+        let h = h as u16;
+        let f = convert_half_to_float(h);
+        if f.is_nan() {
+            assert!(convert_float_to_half(f) & 0x7FFF > 0x7C00);
+        } else {
+            assert_eq!(convert_float_to_half(f), h);
+        }
+    }
+}
+
+#[test]
+fn stream_conversion() {
+    let floats = [0.0, 1.0, -2.0, 0.5];
+    let mut halves = [0; 4];
+    let mut result = [0.0; 4];
+
+    convert_float_to_half_stream(&mut halves, &floats);
+    convert_half_to_float_stream(&mut result, &halves);
+
+    assert_eq!(halves, [0x0000, 0x3C00, 0xC000, 0x3800]);
+    assert_eq!(result, floats);
+}
+
+#[test]
+#[should_panic]
+fn stream_conversion_should_fail_when_lengths_differ() {
+    let mut halves = [0; 3];
+
+    convert_float_to_half_stream(&mut halves, &[0.0, 1.0]);
+}
+
+#[test]
+fn load_and_store_half2() {
+    let v = Vector2 {
+        x: 1.5,
+        y: -0.25,
+    };
+    let h = Half2::store(&v);
+
+    assert_eq!(h, Half2 { x: 0x3E00, y: 0xB400 });
+    assert_eq!(h.load(), v);
+}
+
+#[test]
+fn load_and_store_half4() {
+    let v = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: -3.0,
+        w: 0.5,
+    };
+    let h = Half4::store(&v);
+
+    assert_eq!(h, Half4 { x: 0x3C00, y: 0x4000, z: 0xC200, w: 0x3800 });
+    assert_eq!(h.load(), v);
+}
@@ -1,6 +1,7 @@
 extern crate xmath;
 
-use xmath::Matrix;
+use std::f32;
+use xmath::{Handedness, Matrix, Vector4};
 
 #[test]
 fn create_zero_filled_matrix() {
@@ -72,3 +73,270 @@ fn indexing() {
     let row3 = matrix[3];
     assert_eq!(row3, [ 41.0, 43.0, 47.0, 53.0 ]);
 }
+
+#[test]
+fn determinant() {
+    let matrix = Matrix::new(
+        2.0, 3.0, 5.0, 7.0,
+        11.0, 13.0, 17.0, 19.0,
+        23.0, 29.0, 31.0, 37.0,
+        41.0, 43.0, 47.0, 53.0,
+    );
+
+    assert_eq!(matrix.determinant(), 880.0);
+    assert_eq!(Matrix::identity().determinant(), 1.0);
+    assert_eq!(Matrix::zero().determinant(), 0.0);
+}
+
+#[test]
+fn inverse() {
+    let matrix = Matrix::rotation_y(0.7) * Matrix::translation(1.0, -2.0, 3.0) * Matrix::perspective_fov(1.2, 1.5, 0.1, 100.0);
+    let product = &matrix * &matrix.inverse();
+    let identity = Matrix::identity();
+
+    for r in 0..4 {
+        for c in 0..4 {
+            assert!((product[r][c] - identity[r][c]).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn inverse_of_singular_matrix_is_not_finite() {
+    let inverse = Matrix::zero().inverse();
+
+    assert!(!inverse[0][0].is_finite());
+}
+
+fn primes() -> Matrix {
+    Matrix::new(
+        2.0, 3.0, 5.0, 7.0,
+        11.0, 13.0, 17.0, 19.0,
+        23.0, 29.0, 31.0, 37.0,
+        41.0, 43.0, 47.0, 53.0,
+    )
+}
+
+fn sequence() -> Matrix {
+    Matrix::new(
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+        13.0, 14.0, 15.0, 16.0,
+    )
+}
+
+#[test]
+fn add() {
+    let expected = Matrix::new(
+        3.0, 5.0, 8.0, 11.0,
+        16.0, 19.0, 24.0, 27.0,
+        32.0, 39.0, 42.0, 49.0,
+        54.0, 57.0, 62.0, 69.0,
+    );
+
+    assert_eq!(primes() + sequence(), expected);
+    assert_eq!(&primes() + &sequence(), expected);
+    assert_eq!(primes() + &sequence(), expected);
+    assert_eq!(&primes() + sequence(), expected);
+}
+
+#[test]
+fn sub() {
+    let expected = Matrix::new(
+        1.0, 1.0, 2.0, 3.0,
+        6.0, 7.0, 10.0, 11.0,
+        14.0, 19.0, 20.0, 25.0,
+        28.0, 29.0, 32.0, 37.0,
+    );
+
+    assert_eq!(primes() - sequence(), expected);
+    assert_eq!(&primes() - &sequence(), expected);
+    assert_eq!(&primes() - &primes(), Matrix::zero());
+}
+
+#[test]
+fn neg() {
+    let m = -sequence();
+
+    assert_eq!(m[0], [-1.0, -2.0, -3.0, -4.0]);
+    assert_eq!(m[3], [-13.0, -14.0, -15.0, -16.0]);
+    assert_eq!(-&m, sequence());
+}
+
+#[test]
+fn scale() {
+    let doubled = sequence() * 2.0;
+
+    assert_eq!(doubled[1], [10.0, 12.0, 14.0, 16.0]);
+    assert_eq!(&doubled / 2.0, sequence());
+    assert_eq!(doubled / 4.0 * 2.0, sequence());
+}
+
+#[test]
+fn assign_operators() {
+    let mut m = primes();
+    m += sequence();
+    m -= &sequence();
+    assert_eq!(m, primes());
+
+    m *= 3.0;
+    m /= 3.0;
+    assert_eq!(m, primes());
+
+    m *= &Matrix::identity();
+    assert_eq!(m, primes());
+
+    m *= sequence();
+    assert_eq!(m, primes() * sequence());
+}
+
+#[test]
+fn index_mut() {
+    let mut m = Matrix::zero();
+    m[2][1] = 5.0;
+    m[3] = [1.0, 2.0, 3.0, 4.0];
+
+    assert_eq!(m[2], [0.0, 5.0, 0.0, 0.0]);
+    assert_eq!(m[3], [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn rows_and_columns() {
+    let mut m = primes();
+
+    assert_eq!(m.row(1), Vector4 { x: 11.0, y: 13.0, z: 17.0, w: 19.0 });
+    assert_eq!(m.column(2), Vector4 { x: 5.0, y: 17.0, z: 31.0, w: 47.0 });
+
+    let v = Vector4 { x: -1.0, y: -2.0, z: -3.0, w: -4.0 };
+    m.set_row(0, &v);
+    assert_eq!(m[0], [-1.0, -2.0, -3.0, -4.0]);
+
+    m.set_column(3, &v);
+    assert_eq!(m.column(3), v);
+    assert_eq!(m[1], [11.0, 13.0, 17.0, -2.0]);
+}
+
+#[test]
+fn product() {
+    let ms = [Matrix::rotation_x(0.3), Matrix::translation(1.0, 2.0, 3.0), primes()];
+
+    assert_eq!(ms.iter().product::<Matrix>(), &(&ms[0] * &ms[1]) * &ms[2]);
+    assert_eq!(ms.iter().cloned().product::<Matrix>(), &(&ms[0] * &ms[1]) * &ms[2]);
+    assert_eq!(Vec::<Matrix>::new().into_iter().product::<Matrix>(), Matrix::identity());
+}
+
+#[test]
+fn transpose_mut() {
+    let mut m = primes();
+    m.transpose_mut();
+
+    assert_eq!(m, primes().transpose());
+    assert_eq!(m[0], [2.0, 11.0, 23.0, 41.0]);
+
+    m.transpose_mut();
+    assert_eq!(m, primes());
+}
+
+#[test]
+fn multiply_transpose() {
+    let a = Matrix::rotation_y(0.7) * Matrix::translation(1.0, -2.0, 3.0);
+    let b = Matrix::perspective_fov(1.2, 1.5, 0.1, 100.0);
+
+    assert_eq!(Matrix::multiply_transpose(&a, &b), (&a * &b).transpose());
+    assert_eq!(Matrix::multiply_transpose(&primes(), &sequence()), (primes() * sequence()).transpose());
+}
+
+#[test]
+fn vector_tensor_product() {
+    let v1 = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    let v2 = Vector4 { x: -1.0, y: 0.5, z: 2.0, w: 0.0 };
+    let m = Matrix::vector_tensor_product(&v1, &v2);
+
+    assert_eq!(m[0], [-1.0, 0.5, 2.0, 0.0]);
+    assert_eq!(m[2], [-3.0, 1.5, 6.0, 0.0]);
+
+    // The outer product of column vectors, with the row vector convention of Matrix
+    let column = |v: &Vector4| Matrix::new(
+        v.x, 0.0, 0.0, 0.0,
+        v.y, 0.0, 0.0, 0.0,
+        v.z, 0.0, 0.0, 0.0,
+        v.w, 0.0, 0.0, 0.0,
+    );
+    assert_eq!(m, column(&v1) * column(&v2).transpose());
+}
+
+#[test]
+fn is_identity() {
+    assert!(Matrix::identity().is_identity());
+    assert!((Matrix::rotation_z(0.0) * Matrix::translation(0.0, 0.0, 0.0)).is_identity());
+    assert!(!Matrix::translation(0.0, 1e-7, 0.0).is_identity());
+    assert!(!Matrix::zero().is_identity());
+}
+
+#[test]
+fn is_nan_and_is_infinite() {
+    let mut m = Matrix::identity();
+    assert!(!m.is_nan());
+    assert!(!m.is_infinite());
+
+    m[2][3] = f32::INFINITY;
+    assert!(!m.is_nan());
+    assert!(m.is_infinite());
+
+    m[1][0] = f32::NAN;
+    assert!(m.is_nan());
+    assert!(Matrix::zero().inverse().is_nan());
+}
+
+#[test]
+fn near_equal() {
+    let m = Matrix::rotation_x(0.5);
+    let mut n = m.clone();
+    n[1][2] += 1e-5;
+
+    assert!(m.near_equal(&n, 1e-4));
+    assert!(!m.near_equal(&n, 1e-6));
+    assert!(m.near_equal(&m, 0.0));
+
+    n[0][0] = f32::NAN;
+    assert!(!m.near_equal(&n, 1.0));
+}
+
+#[test]
+fn is_affine() {
+    assert!(Matrix::identity().is_affine(0.0));
+    assert!((Matrix::rotation_y(1.0) * Matrix::translation(1.0, 2.0, 3.0) * 2.0 / 2.0).is_affine(1e-6));
+    assert!(!Matrix::perspective_fov(1.2, 1.5, 0.1, 100.0).is_affine(1e-3));
+    assert!(!(Matrix::identity() * 2.0).is_affine(1e-3));
+}
+
+#[test]
+fn is_orthonormal() {
+    let rotation = Matrix::rotation_x(0.3) * Matrix::rotation_y(-1.2) * Matrix::rotation_z(2.0);
+
+    assert!(rotation.is_orthonormal(1e-5));
+    assert!((rotation.clone() * Matrix::translation(5.0, 6.0, 7.0)).is_orthonormal(1e-5));
+    assert!(!(rotation * 2.0).is_orthonormal(1e-5));
+
+    let shear = Matrix::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.5, 1.0, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    );
+    assert!(!shear.is_orthonormal(1e-5));
+}
+
+#[test]
+fn handedness() {
+    let mut mirror = Matrix::identity();
+    mirror[2][2] = -1.0;
+
+    assert_eq!(Matrix::identity().handedness(), Handedness::Preserved);
+    assert_eq!(Matrix::rotation_y(2.5).handedness(), Handedness::Preserved);
+    assert_eq!(mirror.handedness(), Handedness::Mirrored);
+    assert_eq!((Matrix::rotation_x(0.4) * &mirror * 3.0).handedness(), Handedness::Mirrored);
+    assert_eq!((Matrix::identity() * -1.0).handedness(), Handedness::Mirrored);
+    assert_eq!(Matrix::zero().handedness(), Handedness::Singular);
+}
@@ -0,0 +1,98 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector2, Vector3};
+
+// Fibonacci sphere, covering every octant and both poles
+fn normals() -> Vec<Vector3> {
+    let count = 2000;
+    let golden_angle = f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    let mut normals: Vec<Vector3> = (0..count).map(|i| {
+        let z = 1.0 - 2.0 * (i as f32 + 0.5) / count as f32;
+        let r = (1.0 - z * z).sqrt();
+        let (sin, cos) = (golden_angle * i as f32).sin_cos();
+        Vector3 { x: r * cos, y: r * sin, z }
+    }).collect();
+    normals.push(Vector3 { x: 0.0, y: 0.0, z: 1.0 });
+    normals.push(Vector3 { x: 0.0, y: 0.0, z: -1.0 });
+    normals.push(Vector3 { x: 1.0, y: 0.0, z: 0.0 });
+    normals.push(Vector3 { x: 0.0, y: -1.0, z: 0.0 });
+    normals
+}
+
+// atan2 keeps its precision for small angles, unlike acos
+fn angle(v1: &Vector3, v2: &Vector3) -> f32 {
+    let cross = Vector3 {
+        x: v1.y * v2.z - v1.z * v2.y,
+        y: v1.z * v2.x - v1.x * v2.z,
+        z: v1.x * v2.y - v1.y * v2.x,
+    };
+    cross.length().atan2(v1.dot(v2))
+}
+
+#[test]
+fn octahedral_encoding_stays_in_unit_square() {
+    for n in normals().iter() {
+        let e = n.encode_octahedral();
+        assert!(e.x.abs() <= 1.0 && e.y.abs() <= 1.0);
+    }
+}
+
+#[test]
+fn octahedral_encoding_of_axes() {
+    assert_eq!(Vector3 { x: 0.0, y: 0.0, z: 1.0 }.encode_octahedral(), Vector2 { x: 0.0, y: 0.0 });
+    assert_eq!(Vector3 { x: 1.0, y: 0.0, z: 0.0 }.encode_octahedral(), Vector2 { x: 1.0, y: 0.0 });
+    assert_eq!(Vector3 { x: 0.0, y: -1.0, z: 0.0 }.encode_octahedral(), Vector2 { x: 0.0, y: -1.0 });
+    assert_eq!(Vector3 { x: 0.0, y: 0.0, z: -1.0 }.encode_octahedral(), Vector2 { x: 1.0, y: 1.0 });
+}
+
+#[test]
+fn octahedral_round_trip() {
+    for n in normals().iter() {
+        let decoded = Vector3::decode_octahedral(&n.encode_octahedral());
+        assert!((decoded.length() - 1.0).abs() < 1e-6);
+        assert!(angle(n, &decoded) < 1e-5, "{:?} -> {:?}", n, decoded);
+    }
+}
+
+#[test]
+fn octahedral_snorm16_round_trip() {
+    let max_error = 0.005f32.to_radians();
+    for n in normals().iter() {
+        let decoded = Vector3::decode_octahedral_snorm16(&n.encode_octahedral_snorm16());
+        assert!(angle(n, &decoded) < max_error, "{:?} -> {:?}", n, decoded);
+    }
+}
+
+#[test]
+fn octahedral_snorm8_round_trip() {
+    let max_error = 1.0f32.to_radians();
+    for n in normals().iter() {
+        let decoded = Vector3::decode_octahedral_snorm8(&n.encode_octahedral_snorm8());
+        assert!(angle(n, &decoded) < max_error, "{:?} -> {:?}", n, decoded);
+    }
+}
+
+#[test]
+fn spheremap_encoding_stays_in_unit_square() {
+    for n in normals().iter().filter(|n| n.z > -1.0) {
+        let e = n.encode_spheremap();
+        assert!(0.0 <= e.x && e.x <= 1.0);
+        assert!(0.0 <= e.y && e.y <= 1.0);
+    }
+}
+
+#[test]
+fn spheremap_encoding_of_z_axis() {
+    assert_eq!(Vector3 { x: 0.0, y: 0.0, z: 1.0 }.encode_spheremap(), Vector2 { x: 0.5, y: 0.5 });
+    assert_eq!(Vector3::decode_spheremap(&Vector2 { x: 0.5, y: 0.5 }), Vector3 { x: 0.0, y: 0.0, z: 1.0 });
+}
+
+#[test]
+fn spheremap_round_trip() {
+    for n in normals().iter().filter(|n| n.z > -0.999) {
+        let decoded = Vector3::decode_spheremap(&n.encode_spheremap());
+        assert!((decoded.length() - 1.0).abs() < 1e-4);
+        assert!(angle(n, &decoded) < 1e-3, "{:?} -> {:?}", n, decoded);
+    }
+}
@@ -0,0 +1,256 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector2, Vector3, Vector4};
+use xmath::packed::*;
+
+fn assert_near(v1: &Vector4, v2: &Vector4, epsilon: f32) {
+    assert!((v1.x - v2.x).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.y - v2.y).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.z - v2.z).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.w - v2.w).abs() <= epsilon, "{:?} != {:?}", v1, v2);
+}
+
+#[test]
+fn ubyten4_round_trip() {
+    let v = Vector4 { x: 0.0, y: 0.25, z: 0.5, w: 1.0 };
+    let p = UByteN4::store(&v);
+
+    assert_eq!(p, UByteN4 { x: 0, y: 64, z: 128, w: 255 });
+    assert_near(&p.load(), &v, 1.0 / 255.0);
+}
+
+#[test]
+fn ubyten4_saturates() {
+    let p = UByteN4::store(&Vector4 { x: -1.0, y: 2.0, z: f32::NAN, w: f32::INFINITY });
+
+    assert_eq!(p, UByteN4 { x: 0, y: 255, z: 0, w: 255 });
+}
+
+#[test]
+fn byten4_round_trip() {
+    let v = Vector4 { x: -1.0, y: -0.5, z: 0.5, w: 1.0 };
+    let p = ByteN4::store(&v);
+
+    assert_eq!(p, ByteN4 { x: -127, y: -64, z: 64, w: 127 });
+    assert_near(&p.load(), &v, 1.0 / 127.0);
+}
+
+#[test]
+fn byten4_saturates() {
+    let p = ByteN4::store(&Vector4 { x: -3.0, y: 3.0, z: f32::NAN, w: f32::NEG_INFINITY });
+
+    assert_eq!(p, ByteN4 { x: -127, y: 127, z: 0, w: -127 });
+    assert_eq!(ByteN4 { x: -128, y: 0, z: 0, w: 0 }.load().x, -1.0);
+}
+
+#[test]
+fn byten2_round_trip() {
+    let v = Vector2 { x: -1.0, y: 0.5 };
+    let p = ByteN2::store(&v);
+
+    assert_eq!(p, ByteN2 { x: -127, y: 64 });
+    assert!((p.load().y - v.y).abs() <= 1.0 / 127.0);
+    assert_eq!(ByteN2::store(&Vector2 { x: 2.0, y: -2.0 }), ByteN2 { x: 127, y: -127 });
+}
+
+#[test]
+fn shortn2_round_trip() {
+    let v = Vector2 { x: -0.75, y: 0.125 };
+    let p = ShortN2::store(&v);
+
+    assert_eq!(p.load().x, (-0.75f32 * 32767.0).round() / 32767.0);
+    assert!((p.load().y - v.y).abs() <= 1.0 / 32767.0);
+    assert_eq!(ShortN2::store(&Vector2 { x: 2.0, y: -2.0 }), ShortN2 { x: 32767, y: -32767 });
+}
+
+#[test]
+fn shortn4_round_trip() {
+    let v = Vector4 { x: -1.0, y: -0.3, z: 0.3, w: 1.0 };
+    let p = ShortN4::store(&v);
+
+    assert_eq!(p.x, -32767);
+    assert_eq!(p.w, 32767);
+    assert_near(&p.load(), &v, 1.0 / 32767.0);
+    assert_eq!(ShortN4 { x: -32768, y: 0, z: 0, w: 0 }.load().x, -1.0);
+}
+
+#[test]
+fn ushortn4_round_trip() {
+    let v = Vector4 { x: 0.0, y: 0.1, z: 0.9, w: 1.0 };
+    let p = UShortN4::store(&v);
+
+    assert_eq!(p.x, 0);
+    assert_eq!(p.w, 65535);
+    assert_near(&p.load(), &v, 1.0 / 65535.0);
+    assert_eq!(UShortN4::store(&Vector4 { x: -1.0, y: 5.0, z: 0.0, w: 0.0 }), UShortN4 { x: 0, y: 65535, z: 0, w: 0 });
+}
+
+#[test]
+fn color_is_bgra() {
+    let p = Color::store(&Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 1.0 });
+    assert_eq!(p.c, 0xFFFF0000);
+
+    let p = Color::store(&Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 0.0 });
+    assert_eq!(p.c, 0x000000FF);
+}
+
+#[test]
+fn color_round_trip() {
+    let v = Vector4 { x: 0.2, y: 0.4, z: 0.6, w: 0.8 };
+
+    assert_near(&Color::store(&v).load(), &v, 1.0 / 255.0);
+    assert_eq!(Color::store(&Vector4 { x: 2.0, y: -1.0, z: 0.0, w: 9.0 }).c, 0xFFFF0000);
+}
+
+#[test]
+fn u565_round_trip() {
+    let v = Vector3 { x: 31.0, y: 32.0, z: 7.0 };
+    let p = U565::store(&v);
+
+    assert_eq!(p.v, 31 | (32 << 5) | (7 << 11));
+    assert_eq!(p.load(), v);
+}
+
+#[test]
+fn u565_saturates() {
+    let p = U565::store(&Vector3 { x: 40.0, y: 100.0, z: -3.0 });
+
+    assert_eq!(p.load(), Vector3 { x: 31.0, y: 63.0, z: 0.0 });
+}
+
+#[test]
+fn u555_round_trip() {
+    let v = Vector4 { x: 1.0, y: 2.0, z: 30.0, w: 1.0 };
+    let p = U555::store(&v);
+
+    assert_eq!(p.v, 1 | (2 << 5) | (30 << 10) | 0x8000);
+    assert_eq!(p.load(), v);
+    assert_eq!(U555::store(&Vector4 { x: 99.0, y: -1.0, z: 0.0, w: 4.0 }).load(), Vector4 { x: 31.0, y: 0.0, z: 0.0, w: 1.0 });
+}
+
+#[test]
+fn unibble4_round_trip() {
+    let v = Vector4 { x: 1.0, y: 5.0, z: 10.0, w: 15.0 };
+    let p = UNibble4::store(&v);
+
+    assert_eq!(p.v, 0xFA51);
+    assert_eq!(p.load(), v);
+    assert_eq!(UNibble4::store(&Vector4 { x: 16.0, y: -1.0, z: 0.0, w: 0.0 }).load(), Vector4 { x: 15.0, y: 0.0, z: 0.0, w: 0.0 });
+}
+
+#[test]
+fn ties_round_to_even() {
+    let p = UNibble4::store(&Vector4 { x: 2.5, y: 3.5, z: 0.5, w: 14.5 });
+    assert_eq!(p.load(), Vector4 { x: 2.0, y: 4.0, z: 0.0, w: 14.0 });
+
+    let p = UByteN4::store(&Vector4 { x: 2.5 / 255.0, y: 0.5 / 255.0, z: 6.5 / 255.0, w: 1.0 });
+    assert_eq!((p.x, p.y, p.z, p.w), (2, 0, 6, 255));
+
+    let p = ByteN4::store(&Vector4 { x: -2.5 / 127.0, y: 4.5 / 127.0, z: 0.0, w: 0.0 });
+    assert_eq!((p.x, p.y), (-2, 4));
+}
+
+#[test]
+fn xdecn4_round_trip() {
+    let v = Vector4 { x: -1.0, y: -0.5, z: 1.0, w: 2.0 / 3.0 };
+    let p = XDecN4::store(&v);
+
+    assert_eq!(p.v >> 30, 2);
+    assert_near(&p.load(), &v, 1.0 / 511.0);
+}
+
+#[test]
+fn xdecn4_saturates() {
+    let p = XDecN4::store(&Vector4 { x: -5.0, y: 5.0, z: 0.0, w: -1.0 });
+
+    assert_eq!(p.load(), Vector4 { x: -1.0, y: 1.0, z: 0.0, w: 0.0 });
+    assert_eq!(XDecN4 { v: 0x200 }.load().x, -1.0);
+}
+
+#[test]
+fn udecn4_round_trip() {
+    let v = Vector4 { x: 0.0, y: 0.5, z: 1.0, w: 1.0 / 3.0 };
+    let p = UDecN4::store(&v);
+
+    assert_eq!(p.v & 0x3FF, 0);
+    assert_eq!((p.v >> 20) & 0x3FF, 1023);
+    assert_eq!(p.v >> 30, 1);
+    assert_near(&p.load(), &v, 1.0 / 1023.0);
+    assert_eq!(UDecN4::store(&Vector4 { x: 2.0, y: -1.0, z: 0.0, w: 2.0 }).load(), Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 1.0 });
+}
+
+#[test]
+fn float3pk_round_trip() {
+    let v = Vector3 { x: 1.0, y: 0.5, z: 1024.0 };
+    let p = Float3PK::store(&v);
+
+    assert_eq!(p.v & 0x7FF, 15 << 6);
+    assert_eq!(p.load(), v);
+}
+
+#[test]
+fn float3pk_precision() {
+    let v = Vector3 { x: 3.3, y: 0.001, z: 100.3 };
+    let r = Float3PK::store(&v).load();
+
+    assert!((r.x - v.x).abs() <= v.x / 64.0);
+    assert!((r.y - v.y).abs() <= v.y / 64.0);
+    assert!((r.z - v.z).abs() <= v.z / 32.0);
+}
+
+#[test]
+fn float3pk_denormal() {
+    let smallest_x = 2.0f32.powi(-20);
+    let smallest_z = 2.0f32.powi(-19);
+    let p = Float3PK::store(&Vector3 { x: smallest_x, y: 0.0, z: smallest_z });
+
+    assert_eq!(p.v, 1 | (1 << 22));
+    assert_eq!(p.load(), Vector3 { x: smallest_x, y: 0.0, z: smallest_z });
+}
+
+#[test]
+fn float3pk_saturates() {
+    let p = Float3PK::store(&Vector3 { x: -1.0, y: 1.0e10, z: 1.0e10 });
+    let r = p.load();
+
+    assert_eq!(r.x, 0.0);
+    assert_eq!(r.y, 65024.0);
+    assert_eq!(r.z, 64512.0);
+}
+
+#[test]
+fn float3pk_infinity_and_nan() {
+    let p = Float3PK::store(&Vector3 { x: f32::INFINITY, y: f32::NEG_INFINITY, z: f32::NAN });
+    let r = p.load();
+
+    assert_eq!(r.x, f32::INFINITY);
+    assert_eq!(r.y, 0.0);
+    assert!(r.z.is_nan());
+}
+
+#[test]
+fn float3se_round_trip() {
+    let v = Vector3 { x: 1.0, y: 0.5, z: 0.25 };
+    let p = Float3SE::store(&v);
+
+    assert_eq!(p.load(), v);
+}
+
+#[test]
+fn float3se_precision() {
+    let v = Vector3 { x: 100.0, y: 3.3, z: 57.7 };
+    let r = Float3SE::store(&v).load();
+    let step = 128.0 / 512.0;
+
+    assert!((r.x - v.x).abs() <= step);
+    assert!((r.y - v.y).abs() <= step);
+    assert!((r.z - v.z).abs() <= step);
+}
+
+#[test]
+fn float3se_saturates() {
+    let r = Float3SE::store(&Vector3 { x: -1.0, y: 1.0e10, z: f32::NAN }).load();
+
+    assert_eq!(r, Vector3 { x: 0.0, y: 65408.0, z: 0.0 });
+}
@@ -0,0 +1,99 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector, Vector3, Vector4, Matrix};
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn assert_near(a: &Vector3, b: &Vector3) {
+    assert!((*a - *b).length() < 1e-5, "{:?} != {:?}", a, b);
+}
+
+fn assert_near_matrix(a: &Matrix, b: &Matrix) {
+    for r in 0..4 {
+        for c in 0..4 {
+            assert!((a[r][c] - b[r][c]).abs() < 1e-5, "{:?} != {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn identity_does_not_rotate() {
+    let p = v(1.0, -2.0, 3.0);
+
+    assert_eq!(p.rotate(&Vector4::quaternion_identity()), p);
+    assert_eq!(Matrix::rotation_quaternion(&Vector4::quaternion_identity()), Matrix::identity());
+}
+
+#[test]
+fn rotation_axis() {
+    let q = Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 2.0), f32::consts::FRAC_PI_2);
+
+    assert_near(&v(1.0, 0.0, 0.0).rotate(&q), &v(0.0, 1.0, 0.0));
+    assert_near(&v(0.0, 1.0, 0.0).rotate(&q), &v(-1.0, 0.0, 0.0));
+    assert_near(&v(0.0, 0.0, 1.0).rotate(&q), &v(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn rotation_axis_matches_rotation_matrices() {
+    let angle = 0.7;
+    let x = Vector4::quaternion_rotation_axis(&v(1.0, 0.0, 0.0), angle);
+    let y = Vector4::quaternion_rotation_axis(&v(0.0, 1.0, 0.0), angle);
+    let z = Vector4::quaternion_rotation_axis(&v(0.0, 0.0, 1.0), angle);
+
+    assert_near_matrix(&Matrix::rotation_quaternion(&x), &Matrix::rotation_x(angle));
+    assert_near_matrix(&Matrix::rotation_quaternion(&y), &Matrix::rotation_y(angle));
+    assert_near_matrix(&Matrix::rotation_quaternion(&z), &Matrix::rotation_z(angle));
+}
+
+#[test]
+fn rotate_matches_matrix_transform() {
+    let q = Vector4::quaternion_rotation_axis(&v(1.0, 2.0, -1.0), 2.5);
+    let p = v(0.3, -1.2, 4.0);
+
+    assert_near(&p.rotate(&q), &p.transform(&Matrix::rotation_quaternion(&q)));
+}
+
+#[test]
+fn inverse_rotate() {
+    let q = Vector4::quaternion_rotation_axis(&v(1.0, 2.0, -1.0), 2.5);
+    let p = v(0.3, -1.2, 4.0);
+
+    assert_near(&p.rotate(&q).inverse_rotate(&q), &p);
+}
+
+#[test]
+fn multiply_applies_self_first() {
+    let q1 = Vector4::quaternion_rotation_axis(&v(1.0, 0.0, 0.0), 0.4);
+    let q2 = Vector4::quaternion_rotation_axis(&v(0.0, 1.0, 1.0), 1.3);
+    let p = v(0.3, -1.2, 4.0);
+
+    assert_near(&p.rotate(&q1.quaternion_multiply(&q2)), &p.rotate(&q1).rotate(&q2));
+    assert_near_matrix(&Matrix::rotation_quaternion(&q1.quaternion_multiply(&q2)),
+                       &(Matrix::rotation_quaternion(&q1) * Matrix::rotation_quaternion(&q2)));
+}
+
+#[test]
+fn conjugate() {
+    let q = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+
+    assert_eq!(q.quaternion_conjugate(), Vector4 { x: -1.0, y: -2.0, z: -3.0, w: 4.0 });
+}
+
+#[test]
+fn rotation_matrix_round_trip() {
+    let axes = [v(1.0, 0.0, 0.0), v(0.0, 1.0, 0.0), v(0.0, 0.0, 1.0), v(1.0, -2.0, 0.5)];
+    let angles = [0.0, 0.5, 2.0, 3.1, -2.9];
+
+    for axis in axes.iter() {
+        for angle in angles.iter() {
+            let m = Matrix::rotation_quaternion(&Vector4::quaternion_rotation_axis(axis, *angle));
+            let q = Vector4::quaternion_rotation_matrix(&m);
+
+            assert!((q.length() - 1.0).abs() < 1e-5);
+            assert_near_matrix(&Matrix::rotation_quaternion(&q), &m);
+        }
+    }
+}
@@ -0,0 +1,507 @@
+extern crate xmath;
+
+use std::f32::consts::PI;
+use xmath::{Vector, Vector3, Vector4, Matrix};
+use xmath::sh;
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn directions() -> Vec<Vector3> {
+    vec![
+        v(0.0, 0.0, 1.0),
+        v(0.0, 0.0, -1.0),
+        v(1.0, 0.0, 0.0),
+        v(0.3, -0.4, 0.5).normalize(),
+        v(-0.8, 0.1, -0.2).normalize(),
+        v(0.2, 0.9, 0.1).normalize(),
+    ]
+}
+
+fn eval(order: usize, dir: &Vector3) -> Vec<f32> {
+    let mut result = vec![0.0; order * order];
+    sh::eval_direction(&mut result, order, dir);
+    result
+}
+
+fn assert_near(a: &[f32], b: &[f32], epsilon: f32) {
+    assert_eq!(a.len(), b.len());
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((x - y).abs() < epsilon, "{:?} != {:?}", a, b);
+    }
+}
+
+// Legendre polynomial of degree l
+fn legendre(l: usize, x: f32) -> f32 {
+    let (mut p0, mut p1) = (1.0, x);
+    if l == 0 {
+        return p0;
+    }
+    for n in 1..l {
+        let p2 = ((2 * n + 1) as f32 * x * p1 - n as f32 * p0) / (n + 1) as f32;
+        p0 = p1;
+        p1 = p2;
+    }
+    p1
+}
+
+#[test]
+fn eval_direction_matches_analytic_basis() {
+    for dir in directions().iter() {
+        let (x, y, z) = (dir.x, dir.y, dir.z);
+        let expected = [
+            0.2820948,
+            -0.48860252 * y,
+            0.48860252 * z,
+            -0.48860252 * x,
+            1.0925485 * x * y,
+            -1.0925485 * y * z,
+            0.9461747 * z * z - 0.31539157,
+            -1.0925485 * x * z,
+            0.54627424 * (x * x - y * y),
+            -0.5900436 * y * (3.0 * x * x - y * y),
+            2.8906114 * x * y * z,
+            -0.4570458 * y * (5.0 * z * z - 1.0),
+            0.37317634 * z * (5.0 * z * z - 3.0),
+            -0.4570458 * x * (5.0 * z * z - 1.0),
+            1.4453057 * z * (x * x - y * y),
+            -0.5900436 * x * (x * x - 3.0 * y * y),
+        ];
+
+        assert_near(&eval(4, dir), &expected, 1e-5);
+    }
+}
+
+#[test]
+fn lower_orders_are_prefixes() {
+    let dir = directions()[3];
+    let full = eval(6, &dir);
+
+    for order in 2..6 {
+        assert_near(&eval(order, &dir), &full[..order * order], 1e-6);
+    }
+}
+
+#[test]
+fn addition_theorem() {
+    // The sum over a band of Y(a) * Y(b) is (2l + 1) / 4pi * P(l, a.b)
+    let dirs = directions();
+    for a in dirs.iter() {
+        for b in dirs.iter() {
+            let (ya, yb) = (eval(6, a), eval(6, b));
+            for l in 0..6 {
+                let band = (l * l)..((l + 1) * (l + 1));
+                let sum: f32 = ya[band.clone()].iter().zip(yb[band].iter()).map(|(x, y)| x * y).sum();
+                let expected = (2 * l + 1) as f32 / (4.0 * PI) * legendre(l, a.dot(b));
+                assert!((sum - expected).abs() < 1e-4, "band {}: {} != {}", l, sum, expected);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn order_below_minimum() {
+    let mut result = [0.0; 1];
+    sh::eval_direction(&mut result, 1, &v(0.0, 0.0, 1.0));
+}
+
+#[test]
+#[should_panic]
+fn order_above_maximum() {
+    let mut result = [0.0; 49];
+    sh::eval_direction(&mut result, 7, &v(0.0, 0.0, 1.0));
+}
+
+#[test]
+#[should_panic]
+fn result_too_short() {
+    let mut result = [0.0; 8];
+    sh::eval_direction(&mut result, 3, &v(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn rotate_moves_directions() {
+    let m = Matrix::rotation_x(0.4) * Matrix::rotation_y(-1.3) * Matrix::rotation_z(2.2);
+
+    for order in 2..7 {
+        for dir in directions().iter() {
+            let mut rotated = vec![0.0; order * order];
+            sh::rotate(&mut rotated, order, &m, &eval(order, dir));
+
+            assert_near(&rotated, &eval(order, &dir.transform(&m)), 1e-4);
+        }
+    }
+}
+
+#[test]
+fn rotate_by_identity() {
+    let input = eval(6, &directions()[4]);
+    let mut result = vec![0.0; 36];
+    sh::rotate(&mut result, 6, &Matrix::identity(), &input);
+
+    assert_near(&result, &input, 1e-5);
+}
+
+#[test]
+fn rotate_z_matches_rotate() {
+    for order in 2..7 {
+        let input = eval(order, &directions()[3]);
+        let mut expected = vec![0.0; order * order];
+        let mut result = vec![0.0; order * order];
+        sh::rotate(&mut expected, order, &Matrix::rotation_z(0.9), &input);
+        sh::rotate_z(&mut result, order, 0.9, &input);
+
+        assert_near(&result, &expected, 1e-4);
+    }
+}
+
+#[test]
+fn add_and_scale() {
+    let a = [1.0, 2.0, 3.0, 4.0];
+    let b = [0.5, -1.0, 0.0, 2.0];
+    let mut result = [0.0; 4];
+
+    sh::add(&mut result, 2, &a, &b);
+    assert_eq!(result, [1.5, 1.0, 3.0, 6.0]);
+
+    sh::scale(&mut result, 2, &a, -2.0);
+    assert_eq!(result, [-2.0, -4.0, -6.0, -8.0]);
+}
+
+#[test]
+fn add_ignores_coefficients_beyond_order() {
+    let a = [1.0; 9];
+    let mut result = [7.0; 9];
+    sh::add(&mut result, 2, &a, &a);
+
+    assert_eq!(result, [2.0, 2.0, 2.0, 2.0, 7.0, 7.0, 7.0, 7.0, 7.0]);
+}
+
+#[test]
+fn dot_matches_addition_theorem() {
+    let dirs = directions();
+    let (a, b) = (dirs[3], dirs[4]);
+    let expected: f32 = (0..3).map(|l| (2 * l + 1) as f32 / (4.0 * PI) * legendre(l, a.dot(&b))).sum();
+
+    assert!((sh::dot(3, &eval(3, &a), &eval(3, &b)) - expected).abs() < 1e-5);
+}
+
+#[test]
+fn multiply_by_constant() {
+    // The constant function 1 has the single coefficient sqrt(4pi)
+    let mut one = vec![0.0; 36];
+    one[0] = (4.0 * PI).sqrt();
+    let g = eval(6, &directions()[5]);
+    let mut result = vec![0.0; 36];
+    sh::multiply(&mut result, 6, &one, &g);
+
+    assert_near(&result, &g, 1e-4);
+}
+
+#[test]
+fn multiply_is_commutative() {
+    for order in 2..7 {
+        let f = eval(order, &directions()[3]);
+        let g = eval(order, &directions()[4]);
+        let (mut fg, mut gf) = (vec![0.0; order * order], vec![0.0; order * order]);
+        sh::multiply(&mut fg, order, &f, &g);
+        sh::multiply(&mut gf, order, &g, &f);
+
+        assert_near(&fg, &gf, 1e-5);
+    }
+}
+
+#[test]
+fn multiply_band_limited_product() {
+    // The product of two order 2 functions is exactly representable in order 3
+    let mut f = vec![0.0; 9];
+    let mut g = vec![0.0; 9];
+    f[..4].copy_from_slice(&[0.5, 0.1, -0.3, 0.2]);
+    g[..4].copy_from_slice(&[1.0, -0.4, 0.2, 0.7]);
+    let mut product = vec![0.0; 9];
+    sh::multiply(&mut product, 3, &f, &g);
+
+    for dir in directions().iter() {
+        let y = eval(3, dir);
+        let value = |c: &[f32]| c.iter().zip(y.iter()).map(|(a, b)| a * b).sum::<f32>();
+
+        assert!((value(&product) - value(&f) * value(&g)).abs() < 1e-5);
+    }
+}
+
+fn color(r: f32, g: f32, b: f32) -> Vector4 {
+    Vector4 { x: r, y: g, z: b, w: 1.0 }
+}
+
+// Radiance reflected by a white diffuse surface with the given normal, which is the lighting
+// convolved with the clamped cosine lobe and divided by pi
+fn exit_radiance(order: usize, coefficients: &[f32], normal: &Vector3) -> f32 {
+    let lobe = [1.0, 2.0 / 3.0, 1.0 / 4.0, 0.0, -1.0 / 24.0, 0.0];
+    let y = eval(order, normal);
+    (0..order * order).map(|i| {
+        let l = (i as f32).sqrt() as usize;
+        lobe[l] * coefficients[i] * y[i]
+    }).sum()
+}
+
+fn lights<F: Fn(&mut [f32], &mut [f32], &mut [f32])>(order: usize, f: F) -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+    let (mut r, mut g, mut b) = (vec![0.0; order * order], vec![0.0; order * order], vec![0.0; order * order]);
+    f(&mut r, &mut g, &mut b);
+    (r, g, b)
+}
+
+#[test]
+fn directional_light_exit_radiance() {
+    let dir = directions()[3];
+
+    for order in 2..7 {
+        let (r, g, b) = lights(order, |r, g, b| sh::eval_directional_light(r, g, b, order, &dir, &color(1.0, 0.5, 2.0)));
+
+        assert!((exit_radiance(order, &r, &dir) - 1.0).abs() < 1e-5);
+        assert!((exit_radiance(order, &g, &dir) - 0.5).abs() < 1e-5);
+        assert!((exit_radiance(order, &b, &dir) - 2.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn directional_light_is_brightest_facing_the_light() {
+    let dir = directions()[4];
+    let (r, _, _) = lights(3, |r, g, b| sh::eval_directional_light(r, g, b, 3, &dir, &color(1.0, 1.0, 1.0)));
+
+    for other in directions().iter() {
+        assert!(exit_radiance(3, &r, other) <= exit_radiance(3, &r, &dir) + 1e-6);
+    }
+}
+
+#[test]
+fn cone_light_exit_radiance() {
+    let dir = directions()[5];
+
+    for order in 2..7 {
+        for radius in [0.1, 0.8, 1.5, 2.5].iter() {
+            let (r, _, _) = lights(order, |r, g, b| sh::eval_cone_light(r, g, b, order, &dir, *radius, &color(3.0, 0.0, 0.0)));
+
+            assert!((exit_radiance(order, &r, &dir) - 3.0).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn narrow_cone_light_approaches_directional_light() {
+    let dir = directions()[3];
+    let (cone, _, _) = lights(6, |r, g, b| sh::eval_cone_light(r, g, b, 6, &dir, 0.001, &color(1.0, 1.0, 1.0)));
+    let (directional, _, _) = lights(6, |r, g, b| sh::eval_directional_light(r, g, b, 6, &dir, &color(1.0, 1.0, 1.0)));
+
+    assert_near(&cone, &directional, 1e-3);
+}
+
+#[test]
+fn zero_radius_lights_are_directional() {
+    let dir = directions()[3];
+    let (directional, _, _) = lights(6, |r, g, b| sh::eval_directional_light(r, g, b, 6, &dir, &color(1.0, 1.0, 1.0)));
+    let (cone, _, _) = lights(6, |r, g, b| sh::eval_cone_light(r, g, b, 6, &dir, 0.0, &color(1.0, 1.0, 1.0)));
+    let (sphere, _, _) = lights(6, |r, g, b| sh::eval_spherical_light(r, g, b, 6, &(dir * 3.0), 0.0, &color(1.0, 1.0, 1.0)));
+
+    assert_near(&cone, &directional, 1e-6);
+    assert_near(&sphere, &directional, 1e-6);
+}
+
+#[test]
+fn cone_light_power_grows_with_solid_angle() {
+    // Constant radiance over the cap, so the DC term is the radiance times the solid angle
+    let dir = directions()[0];
+    let dc = |radius: f32| {
+        let (r, _, _) = lights(3, |r, g, b| sh::eval_cone_light(r, g, b, 3, &dir, radius, &color(1.0, 1.0, 1.0)));
+        r[0]
+    };
+    let solid_angle = |radius: f32| 2.0 * PI * (1.0 - radius.cos());
+
+    let ratio = (dc(1.0) / solid_angle(1.0)) / (dc(0.5) / solid_angle(0.5));
+    assert!(ratio < 1.0);
+    assert!(dc(1.0) > dc(0.5));
+}
+
+#[test]
+fn spherical_light_matches_cone_light() {
+    let position = v(3.0, 4.0, 0.0);
+    let (sphere, _, _) = lights(5, |r, g, b| sh::eval_spherical_light(r, g, b, 5, &position, 2.5, &color(1.0, 1.0, 1.0)));
+    let (cone, _, _) = lights(5, |r, g, b| sh::eval_cone_light(r, g, b, 5, &v(0.6, 0.8, 0.0), (0.5f32).asin(), &color(1.0, 1.0, 1.0)));
+
+    assert_near(&sphere, &cone, 1e-5);
+}
+
+#[test]
+fn spherical_light_exit_radiance() {
+    let position = v(-2.0, 1.0, 5.0);
+    let (r, _, _) = lights(6, |r, g, b| sh::eval_spherical_light(r, g, b, 6, &position, 1.0, &color(0.7, 0.0, 0.0)));
+
+    assert!((exit_radiance(6, &r, &position.normalize()) - 0.7).abs() < 1e-4);
+}
+
+#[test]
+fn inside_spherical_light_is_constant() {
+    // White furnace: a constant environment is reflected unchanged in every direction
+    let (r, _, _) = lights(4, |r, g, b| sh::eval_spherical_light(r, g, b, 4, &v(0.5, 0.0, 0.0), 2.0, &color(1.0, 1.0, 1.0)));
+
+    assert!((r[0] - (4.0 * PI).sqrt()).abs() < 1e-5);
+    assert_near(&r[1..], &[0.0; 15], 1e-5);
+    for dir in directions().iter() {
+        assert!((exit_radiance(4, &r, dir) - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn hemisphere_light_with_equal_colors_is_constant() {
+    let dir = directions()[3];
+    let c = color(0.25, 0.5, 1.0);
+    let (r, g, b) = lights(3, |r, g, b| sh::eval_hemisphere_light(r, g, b, 3, &dir, &c, &c));
+
+    for normal in directions().iter() {
+        assert!((exit_radiance(3, &r, normal) - 0.25).abs() < 1e-5);
+        assert!((exit_radiance(3, &g, normal) - 0.5).abs() < 1e-5);
+        assert!((exit_radiance(3, &b, normal) - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn hemisphere_light_values() {
+    let dir = v(0.0, 0.0, 1.0);
+    let (r, _, _) = lights(2, |r, g, b| sh::eval_hemisphere_light(r, g, b, 2, &dir, &color(1.0, 0.0, 0.0), &color(0.0, 0.0, 0.0)));
+    let radiance = |w: &Vector3| {
+        let y = eval(2, w);
+        r.iter().zip(y.iter()).map(|(a, b)| a * b).sum::<f32>()
+    };
+
+    assert!((radiance(&v(0.0, 0.0, 1.0)) - 1.0).abs() < 1e-5);
+    assert!((radiance(&v(1.0, 0.0, 0.0)) - 0.5).abs() < 1e-5);
+    assert!(radiance(&v(0.0, 0.0, -1.0)).abs() < 1e-5);
+    // Half the sky is half bright on average, seen from the top the surface gets 5/6
+    assert!((exit_radiance(2, &r, &dir) - 5.0 / 6.0).abs() < 1e-5);
+    assert!((exit_radiance(2, &r, &v(0.0, 0.0, -1.0)) - 1.0 / 6.0).abs() < 1e-5);
+}
+
+#[test]
+fn hemisphere_light_clears_higher_bands() {
+    let (mut r, mut g, mut b) = (vec![9.0; 25], vec![9.0; 25], vec![9.0; 25]);
+    sh::eval_hemisphere_light(&mut r, &mut g, &mut b, 5, &v(0.0, 1.0, 0.0), &color(1.0, 1.0, 1.0), &color(0.0, 0.0, 0.0));
+
+    assert_near(&r[4..], &[0.0; 21], 1e-6);
+}
+
+// Direction through the center of a texel, with the Direct3D face layout
+fn texel_direction(face: usize, size: usize, x: usize, y: usize) -> Vector3 {
+    let s = (x as f32 + 0.5) * 2.0 / size as f32 - 1.0;
+    let t = (y as f32 + 0.5) * 2.0 / size as f32 - 1.0;
+    let dir = match face {
+        0 => v(1.0, -t, -s),
+        1 => v(-1.0, -t, s),
+        2 => v(s, 1.0, t),
+        3 => v(s, -1.0, -t),
+        4 => v(s, -t, 1.0),
+        _ => v(-s, -t, -1.0),
+    };
+    dir.normalize()
+}
+
+fn cube_map_faces<F: Fn(&Vector3) -> Vector3>(size: usize, channels: usize, f: F) -> Vec<Vec<f32>> {
+    (0..6).map(|face| {
+        let mut texels = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let c = f(&texel_direction(face, size, x, y));
+                texels.extend_from_slice(&[c.x, c.y, c.z, 1.0][..channels]);
+            }
+        }
+        texels
+    }).collect()
+}
+
+fn project_cube_map(order: usize, size: usize, channels: usize, faces: &[Vec<f32>]) -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+    let cube_map = sh::CubeMap::new(size, channels, [&faces[0], &faces[1], &faces[2], &faces[3], &faces[4], &faces[5]]);
+    let (mut r, mut g, mut b) = (vec![0.0; order * order], vec![0.0; order * order], vec![0.0; order * order]);
+    sh::project_cube_map(&mut r, &mut g, &mut b, order, &cube_map);
+    (r, g, b)
+}
+
+#[test]
+fn constant_cube_map_projects_to_dc() {
+    for &size in [1, 2, 7, 16].iter() {
+        let faces = cube_map_faces(size, 3, |_| v(1.0, 0.5, 0.25));
+        let (r, g, b) = project_cube_map(4, size, 3, &faces);
+
+        let dc = (4.0 * PI).sqrt();
+        let mut expected = vec![0.0; 16];
+        expected[0] = dc;
+        assert_near(&r, &expected, 1e-4);
+        expected[0] = dc * 0.5;
+        assert_near(&g, &expected, 1e-4);
+        expected[0] = dc * 0.25;
+        assert_near(&b, &expected, 1e-4);
+    }
+}
+
+#[test]
+fn cube_map_face_orientation() {
+    let corners = [
+        v(1.0, 0.5, 0.5),
+        v(-1.0, 0.5, -0.5),
+        v(-0.5, 1.0, -0.5),
+        v(-0.5, -1.0, 0.5),
+        v(-0.5, 0.5, 1.0),
+        v(0.5, 0.5, -1.0),
+    ];
+
+    for (face, corner) in corners.iter().enumerate() {
+        // Only the top left texel of the face is lit
+        let mut faces = vec![vec![0.0; 2 * 2 * 3]; 6];
+        faces[face][0] = 1.0;
+        let (r, _, _) = project_cube_map(3, 2, 3, &faces);
+
+        let expected = eval(3, &corner.normalize());
+        let scale = r[0] / expected[0];
+        let expected: Vec<f32> = expected.iter().map(|e| e * scale).collect();
+        assert_near(&r, &expected, 1e-5);
+    }
+}
+
+#[test]
+fn cube_map_projection_reconstructs_low_frequencies() {
+    let coeffs = [0.8, -0.3, 0.5, 0.2, 0.1, -0.15, 0.25, 0.05, -0.1];
+    let faces = cube_map_faces(32, 4, |dir| {
+        let s = sh::dot(3, &coeffs, &eval(3, dir));
+        v(s, 2.0 * s, -s)
+    });
+    let (r, g, b) = project_cube_map(3, 32, 4, &faces);
+
+    assert_near(&r, &coeffs, 5e-3);
+    let doubled: Vec<f32> = coeffs.iter().map(|c| 2.0 * c).collect();
+    assert_near(&g, &doubled, 1e-2);
+    let negated: Vec<f32> = coeffs.iter().map(|c| -c).collect();
+    assert_near(&b, &negated, 5e-3);
+}
+
+#[test]
+fn cube_map_ignores_alpha() {
+    let rgb = cube_map_faces(4, 3, |dir| v(dir.x.max(0.0), dir.y * dir.y, 0.5));
+    let mut rgba = cube_map_faces(4, 4, |dir| v(dir.x.max(0.0), dir.y * dir.y, 0.5));
+    for face in rgba.iter_mut() {
+        for alpha in face.iter_mut().skip(3).step_by(4) {
+            *alpha = 100.0;
+        }
+    }
+
+    let (r0, g0, b0) = project_cube_map(4, 4, 3, &rgb);
+    let (r1, g1, b1) = project_cube_map(4, 4, 4, &rgba);
+    assert_near(&r0, &r1, 1e-6);
+    assert_near(&g0, &g1, 1e-6);
+    assert_near(&b0, &b1, 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn cube_map_face_too_short() {
+    let faces = vec![vec![0.0; 4 * 4 * 3]; 6];
+    sh::CubeMap::new(4, 3, [&faces[0], &faces[1], &faces[2], &faces[3], &faces[4], &faces[5][1..]]);
+}
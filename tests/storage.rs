@@ -0,0 +1,162 @@
+extern crate glium;
+extern crate xmath;
+
+use std::mem;
+use glium::uniforms::{AsUniformValue, UniformValue};
+use std::f32;
+use xmath::{Float2, Float2A, Float3, Float3A, Float4, Float4A, Int2, Int3, Int4, UInt2, UInt3, UInt4};
+use xmath::{Float3x3, Float3x4, Float4x3, Float4x4A, Matrix, Vector, Vector2, Vector3, Vector4};
+
+fn scaling(s: f32) -> Matrix {
+    Matrix::new(
+        s, 0.0, 0.0, 0.0,
+        0.0, s, 0.0, 0.0,
+        0.0, 0.0, s, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    )
+}
+
+fn affine() -> Matrix {
+    scaling(1.5) * Matrix::rotation_x(0.3) * Matrix::rotation_y(-1.1) * Matrix::translation(1.0, -2.0, 3.0)
+}
+
+#[test]
+fn sizes_and_alignment() {
+    assert_eq!(mem::size_of::<Float3x3>(), 36);
+    assert_eq!(mem::size_of::<Float4x3>(), 48);
+    assert_eq!(mem::size_of::<Float3x4>(), 48);
+    assert_eq!(mem::size_of::<Float4x4A>(), 64);
+    assert_eq!(mem::align_of::<Float4x4A>(), 16);
+}
+
+#[test]
+fn float3x3_round_trip() {
+    let m = Matrix::rotation_z(0.7) * scaling(2.0);
+    let stored = Float3x3::store(&m);
+
+    assert_eq!(stored.m[1], [m[1][0], m[1][1], m[1][2]]);
+    assert_eq!(stored.load(), m);
+    assert_eq!(Float3x3::store(&stored.load()), stored);
+}
+
+#[test]
+fn float3x3_drops_translation() {
+    let stored = Float3x3::store(&affine());
+
+    assert_eq!(stored.load()[3], [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(stored.load()[0][3], 0.0);
+}
+
+#[test]
+fn float4x3_round_trip() {
+    let m = affine();
+    let stored = Float4x3::store(&m);
+
+    assert_eq!(stored.m[3], [m[3][0], m[3][1], m[3][2]]);
+    assert_eq!(stored.load(), m);
+}
+
+#[test]
+fn float3x4_is_transposed() {
+    let m = affine();
+    let stored = Float3x4::store(&m);
+
+    // The translation is the last column
+    assert_eq!([stored.m[0][3], stored.m[1][3], stored.m[2][3]], [m[3][0], m[3][1], m[3][2]]);
+    assert_eq!(stored.m[0], [m[0][0], m[1][0], m[2][0], m[3][0]]);
+    assert_eq!(stored.load(), m);
+}
+
+#[test]
+fn float3x4_transforms_column_vectors() {
+    let m = affine();
+    let stored = Float3x4::store(&m);
+    let p = Vector3 { x: 0.5, y: -1.5, z: 2.0 };
+
+    let row = |r: &[f32; 4]| r[0] * p.x + r[1] * p.y + r[2] * p.z + r[3];
+    let transformed = Vector3 { x: row(&stored.m[0]), y: row(&stored.m[1]), z: row(&stored.m[2]) };
+    let expected = p.transform(&m);
+    assert!((transformed - expected).length() < 1e-5);
+}
+
+#[test]
+fn float4x4a_round_trip() {
+    let m = Matrix::perspective_fov(1.2, 1.5, 0.1, 100.0) * affine();
+    let stored = Float4x4A::store(&m);
+
+    assert_eq!(stored.m[2], m[2]);
+    assert_eq!(stored.load(), m);
+}
+
+#[test]
+fn uniform_values() {
+    let m = affine();
+
+    match Float3x3::store(&m).as_uniform_value() {
+        UniformValue::Mat3(v) => assert_eq!(v, Float3x3::store(&m).m),
+        _ => panic!("expected a Mat3"),
+    }
+    match Float4x4A::store(&m).as_uniform_value() {
+        UniformValue::Mat4(v) => assert_eq!(v, [m[0], m[1], m[2], m[3]]),
+        _ => panic!("expected a Mat4"),
+    }
+}
+
+#[test]
+fn vector_sizes_and_alignment() {
+    assert_eq!(mem::size_of::<Float2>(), 8);
+    assert_eq!(mem::size_of::<Float3>(), 12);
+    assert_eq!(mem::size_of::<Float4>(), 16);
+    assert_eq!(mem::align_of::<Float3>(), 4);
+
+    assert_eq!(mem::size_of::<Float2A>(), 16);
+    assert_eq!(mem::size_of::<Float3A>(), 16);
+    assert_eq!(mem::size_of::<Float4A>(), 16);
+    assert_eq!(mem::align_of::<Float2A>(), 16);
+    assert_eq!(mem::align_of::<Float3A>(), 16);
+    assert_eq!(mem::align_of::<Float4A>(), 16);
+
+    assert_eq!(mem::size_of::<Int3>(), 12);
+    assert_eq!(mem::size_of::<UInt4>(), 16);
+}
+
+#[test]
+fn packed_array_layout() {
+    let points = [Float3 { x: 1.0, y: 2.0, z: 3.0 }, Float3 { x: 4.0, y: 5.0, z: 6.0 }];
+    let floats: &[f32; 6] = unsafe { mem::transmute(&points) };
+
+    assert_eq!(*floats, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+}
+
+#[test]
+fn float_round_trip() {
+    let v2 = Vector2 { x: 1.5, y: -2.25 };
+    let v3 = Vector3 { x: 1.5, y: -2.25, z: 1e-30 };
+    let v4 = Vector4 { x: 1.5, y: -2.25, z: 1e30, w: -0.0 };
+
+    assert_eq!(Float2::store(&v2).load(), v2);
+    assert_eq!(Float3::store(&v3).load(), v3);
+    assert_eq!(Float4::store(&v4).load(), v4);
+    assert_eq!(Float2A::store(&v2).load(), v2);
+    assert_eq!(Float3A::store(&v3).load(), v3);
+    assert_eq!(Float4A::store(&v4).load(), v4);
+    assert_eq!(Float3A::store(&v3).y, -2.25);
+}
+
+#[test]
+fn int_load() {
+    assert_eq!(Int2 { x: -3, y: 7 }.load(), Vector2 { x: -3.0, y: 7.0 });
+    assert_eq!(Int3 { x: -3, y: 7, z: 0 }.load(), Vector3 { x: -3.0, y: 7.0, z: 0.0 });
+    assert_eq!(UInt4 { x: 1, y: 2, z: 3, w: 4 }.load(), Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 });
+}
+
+#[test]
+fn int_store_truncates_and_saturates() {
+    let v = Vector4 { x: 2.7, y: -2.7, z: 1e20, w: f32::NAN };
+
+    assert_eq!(Int4::store(&v), Int4 { x: 2, y: -2, z: i32::MAX, w: 0 });
+    assert_eq!(UInt4::store(&v), UInt4 { x: 2, y: 0, z: u32::MAX, w: 0 });
+    assert_eq!(Int2::store(&Vector2 { x: -1e20, y: 0.5 }), Int2 { x: i32::MIN, y: 0 });
+    assert_eq!(UInt3::store(&Vector3 { x: 3.0, y: 4.9, z: -0.5 }), UInt3 { x: 3, y: 4, z: 0 });
+    assert_eq!(UInt2::store(&Vector2 { x: 5.0, y: 6.0 }).load(), Vector2 { x: 5.0, y: 6.0 });
+}
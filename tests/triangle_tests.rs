@@ -0,0 +1,245 @@
+extern crate xmath;
+
+use std::f32;
+use xmath::{Vector3, Vector4, Matrix};
+use xmath::{BoundingFrustum, ContainmentType, PlaneIntersectionType};
+use xmath::triangle_tests;
+
+fn v(x: f32, y: f32, z: f32) -> Vector3 {
+    Vector3 { x, y, z }
+}
+
+fn plane(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+    Vector4 { x, y, z, w }
+}
+
+// Right triangle in the z = 0 plane
+fn triangle() -> [Vector3; 3] {
+    [v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 2.0, 0.0)]
+}
+
+fn ray(origin: Vector3, direction: Vector3) -> Option<f32> {
+    let t = triangle();
+    triangle_tests::intersects_ray(&origin, &direction, &t[0], &t[1], &t[2])
+}
+
+fn triangles(a: [Vector3; 3], b: [Vector3; 3]) -> bool {
+    let ab = triangle_tests::intersects_triangle(&a[0], &a[1], &a[2], &b[0], &b[1], &b[2]);
+    let ba = triangle_tests::intersects_triangle(&b[0], &b[1], &b[2], &a[0], &a[1], &a[2]);
+    assert_eq!(ab, ba);
+    ab
+}
+
+#[test]
+fn ray_hits_front_face() {
+    assert_eq!(ray(v(0.5, 0.5, 3.0), v(0.0, 0.0, -1.0)), Some(3.0));
+}
+
+#[test]
+fn ray_hits_back_face() {
+    assert_eq!(ray(v(0.5, 0.5, -3.0), v(0.0, 0.0, 1.0)), Some(3.0));
+}
+
+#[test]
+fn ray_distance_is_scaled_by_direction_length() {
+    assert_eq!(ray(v(0.5, 0.5, 3.0), v(0.0, 0.0, -2.0)), Some(1.5));
+}
+
+#[test]
+fn ray_hits_at_an_angle() {
+    let dist = ray(v(-1.0, 0.5, 2.0), v(1.0, 0.0, -1.0)).unwrap();
+
+    assert!((dist - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn ray_hits_vertex_and_edge() {
+    assert_eq!(ray(v(0.0, 0.0, 1.0), v(0.0, 0.0, -1.0)), Some(1.0));
+    assert_eq!(ray(v(1.0, 0.0, 1.0), v(0.0, 0.0, -1.0)), Some(1.0));
+    assert_eq!(ray(v(1.0, 1.0, 1.0), v(0.0, 0.0, -1.0)), Some(1.0));
+}
+
+#[test]
+fn ray_misses_outside_triangle() {
+    assert_eq!(ray(v(1.5, 1.5, 1.0), v(0.0, 0.0, -1.0)), None);
+    assert_eq!(ray(v(-0.1, 0.5, 1.0), v(0.0, 0.0, -1.0)), None);
+    assert_eq!(ray(v(0.5, -0.1, 1.0), v(0.0, 0.0, -1.0)), None);
+}
+
+#[test]
+fn ray_pointing_away_misses() {
+    assert_eq!(ray(v(0.5, 0.5, 1.0), v(0.0, 0.0, 1.0)), None);
+}
+
+#[test]
+fn ray_starting_on_triangle() {
+    assert_eq!(ray(v(0.5, 0.5, 0.0), v(0.0, 0.0, 1.0)), Some(0.0));
+}
+
+#[test]
+fn ray_parallel_above_triangle_misses() {
+    assert_eq!(ray(v(-1.0, 0.5, 1.0), v(1.0, 0.0, 0.0)), None);
+}
+
+#[test]
+fn ray_in_plane_hits_entering_edge() {
+    let dist = ray(v(-1.0, 0.5, 0.0), v(1.0, 0.0, 0.0)).unwrap();
+    assert!((dist - 1.0).abs() < 1e-6);
+
+    let dist = ray(v(3.0, 0.5, 0.0), v(-1.0, 0.0, 0.0)).unwrap();
+    assert!((dist - 1.5).abs() < 1e-6);
+}
+
+#[test]
+fn ray_in_plane_starting_inside() {
+    assert_eq!(ray(v(0.5, 0.5, 0.0), v(1.0, 0.0, 0.0)), Some(0.0));
+}
+
+#[test]
+fn ray_in_plane_misses() {
+    assert_eq!(ray(v(-1.0, 3.0, 0.0), v(1.0, 0.0, 0.0)), None);
+    assert_eq!(ray(v(-1.0, 0.5, 0.0), v(-1.0, 0.0, 0.0)), None);
+}
+
+#[test]
+fn ray_misses_degenerate_triangles() {
+    let origin = v(0.5, 0.0, 1.0);
+    let down = v(0.0, 0.0, -1.0);
+
+    assert_eq!(triangle_tests::intersects_ray(&origin, &down, &v(0.0, 0.0, 0.0), &v(1.0, 0.0, 0.0), &v(2.0, 0.0, 0.0)), None);
+    assert_eq!(triangle_tests::intersects_ray(&origin, &down, &v(0.5, 0.0, 0.0), &v(0.5, 0.0, 0.0), &v(0.5, 0.0, 0.0)), None);
+}
+
+#[test]
+fn ray_with_zero_direction_misses() {
+    assert_eq!(ray(v(0.5, 0.5, 1.0), v(0.0, 0.0, 0.0)), None);
+}
+
+#[test]
+fn triangles_crossing() {
+    assert!(triangles(triangle(), [v(0.5, 0.5, -1.0), v(0.5, 0.5, 1.0), v(3.0, 3.0, 0.0)]));
+}
+
+#[test]
+fn triangles_piercing_edge() {
+    // b passes through a, but none of b's vertices is within a
+    assert!(triangles(triangle(), [v(0.2, -1.0, -1.0), v(0.2, 3.0, -1.0), v(0.2, 1.0, 5.0)]));
+}
+
+#[test]
+fn triangles_separated_by_plane() {
+    assert!(!triangles(triangle(), [v(0.0, 0.0, 1.0), v(2.0, 0.0, 1.0), v(0.0, 2.0, 2.0)]));
+}
+
+#[test]
+fn triangles_separated_by_edge_axis() {
+    // b crosses the plane of a beside its hypotenuse
+    assert!(!triangles(triangle(), [v(1.5, 1.5, -1.0), v(1.5, 1.5, 1.0), v(3.0, 3.0, 0.0)]));
+}
+
+#[test]
+fn triangles_touching_at_vertex() {
+    assert!(triangles(triangle(), [v(2.0, 0.0, 0.0), v(3.0, 0.0, 1.0), v(3.0, 1.0, 0.0)]));
+}
+
+#[test]
+fn coplanar_triangles_overlapping() {
+    assert!(triangles(triangle(), [v(0.5, 0.5, 0.0), v(3.0, 0.5, 0.0), v(0.5, 3.0, 0.0)]));
+}
+
+#[test]
+fn coplanar_triangles_contained() {
+    assert!(triangles(triangle(), [v(0.1, 0.1, 0.0), v(0.5, 0.1, 0.0), v(0.1, 0.5, 0.0)]));
+}
+
+#[test]
+fn coplanar_triangles_disjoint() {
+    assert!(!triangles(triangle(), [v(1.5, 1.5, 0.0), v(3.0, 1.5, 0.0), v(1.5, 3.0, 0.0)]));
+    assert!(!triangles(triangle(), [v(-1.0, -1.0, 0.0), v(-0.5, -1.0, 0.0), v(-1.0, 3.0, 0.0)]));
+}
+
+#[test]
+fn identical_triangles() {
+    assert!(triangles(triangle(), triangle()));
+}
+
+#[test]
+fn degenerate_segment_through_triangle() {
+    assert!(triangles(triangle(), [v(0.5, 0.5, -1.0), v(0.5, 0.5, 1.0), v(0.5, 0.5, 0.0)]));
+    assert!(!triangles(triangle(), [v(1.5, 1.5, -1.0), v(1.5, 1.5, 1.0), v(1.5, 1.5, 0.0)]));
+}
+
+#[test]
+fn degenerate_segment_in_plane() {
+    assert!(triangles(triangle(), [v(-1.0, 0.5, 0.0), v(3.0, 0.5, 0.0), v(1.0, 0.5, 0.0)]));
+    assert!(!triangles(triangle(), [v(-1.0, 3.0, 0.0), v(3.0, 3.0, 0.0), v(1.0, 3.0, 0.0)]));
+}
+
+#[test]
+fn degenerate_segment_in_plane_past_vertex() {
+    // Only the axis across the segment within the plane separates these
+    let segment = [v(0.0, 3.05, 0.0), v(3.05, 0.0, 0.0), v(1.525, 1.525, 0.0)];
+    assert!(!triangles(segment, [v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(1.0, 2.0, 0.0)]));
+}
+
+#[test]
+fn degenerate_point() {
+    let inside = v(0.5, 0.5, 0.0);
+    let outside = v(0.5, 0.5, 0.1);
+
+    assert!(triangles(triangle(), [inside, inside, inside]));
+    assert!(!triangles(triangle(), [outside, outside, outside]));
+}
+
+#[test]
+fn both_degenerate() {
+    let segment = [v(-1.0, 0.0, 0.0), v(1.0, 0.0, 0.0), v(0.0, 0.0, 0.0)];
+
+    assert!(triangles(segment, [v(0.0, -1.0, 0.0), v(0.0, 1.0, 0.0), v(0.0, 1.0, 0.0)]));
+    assert!(triangles(segment, [v(0.5, 0.0, 0.0), v(0.5, 0.0, 0.0), v(0.5, 0.0, 0.0)]));
+    assert!(triangles(segment, [v(0.5, 0.0, 0.0), v(3.0, 0.0, 0.0), v(2.0, 0.0, 0.0)]));
+    assert!(!triangles(segment, [v(0.0, -1.0, 0.1), v(0.0, 1.0, 0.1), v(0.0, 0.0, 0.1)]));
+    assert!(!triangles(segment, [v(2.0, 0.0, 0.0), v(3.0, 0.0, 0.0), v(2.5, 0.0, 0.0)]));
+    assert!(!triangles(segment, [v(-1.0, 0.5, 0.0), v(1.0, 0.5, 0.0), v(0.0, 0.5, 0.0)]));
+}
+
+#[test]
+fn triangle_plane() {
+    let t = triangle();
+    let test = |p: Vector4| triangle_tests::intersects_plane(&t[0], &t[1], &t[2], &p);
+
+    assert_eq!(test(plane(0.0, 0.0, 1.0, 1.0)), PlaneIntersectionType::Front);
+    assert_eq!(test(plane(0.0, 0.0, 1.0, -1.0)), PlaneIntersectionType::Back);
+    assert_eq!(test(plane(1.0, 0.0, 0.0, -1.0)), PlaneIntersectionType::Intersecting);
+    assert_eq!(test(plane(0.0, 0.0, 1.0, 0.0)), PlaneIntersectionType::Intersecting);
+    // Touching with a vertex counts as intersecting
+    assert_eq!(test(plane(1.0, 0.0, 0.0, -2.0)), PlaneIntersectionType::Intersecting);
+}
+
+#[test]
+fn contained_by_box_planes() {
+    // The cube [-3, 3]^3
+    let planes = [
+        plane(1.0, 0.0, 0.0, -3.0),
+        plane(-1.0, 0.0, 0.0, -3.0),
+        plane(0.0, 1.0, 0.0, -3.0),
+        plane(0.0, -1.0, 0.0, -3.0),
+        plane(0.0, 0.0, 1.0, -3.0),
+        plane(0.0, 0.0, -1.0, -3.0),
+    ];
+    let test = |a: Vector3, b: Vector3, c: Vector3| triangle_tests::contained_by(&a, &b, &c, &planes);
+
+    assert_eq!(test(v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 2.0, 0.0)), ContainmentType::Contains);
+    assert_eq!(test(v(0.0, 0.0, 0.0), v(5.0, 0.0, 0.0), v(0.0, 2.0, 0.0)), ContainmentType::Intersects);
+    assert_eq!(test(v(4.0, 0.0, 0.0), v(5.0, 0.0, 0.0), v(4.0, 2.0, 0.0)), ContainmentType::Disjoint);
+}
+
+#[test]
+fn contained_by_frustum_planes() {
+    let planes = BoundingFrustum::create_from_matrix(&Matrix::perspective_fov(f32::consts::FRAC_PI_2, 1.0, 1.0, 10.0)).get_planes();
+    let test = |a: Vector3, b: Vector3, c: Vector3| triangle_tests::contained_by(&a, &b, &c, &planes);
+
+    assert_eq!(test(v(0.0, 0.0, -2.0), v(1.0, 0.0, -5.0), v(0.0, 1.0, -5.0)), ContainmentType::Contains);
+    assert_eq!(test(v(0.0, 0.0, -5.0), v(0.0, 0.0, 5.0), v(1.0, 0.0, -5.0)), ContainmentType::Intersects);
+    assert_eq!(test(v(0.0, 0.0, 1.0), v(1.0, 0.0, 1.0), v(0.0, 1.0, 1.0)), ContainmentType::Disjoint);
+}
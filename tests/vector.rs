@@ -1,7 +1,7 @@
 extern crate xmath;
 
 use std::f32;
-use xmath::{Vector, Vector2, Vector3, Vector4, Matrix};
+use xmath::{Vector, Vector2, Vector3, Vector4, Matrix, LineIntersection};
 
 #[test]
 fn zero_of_vector2() {
@@ -1346,3 +1346,730 @@ fn swizzle_of_vector4_should_fail_when_e3_is_more_than_3() {
     };
     let _ = v1.swizzle(1, 3, 0, 4);
 }
+
+#[test]
+fn dot_of_vector2() {
+    let v1 = Vector2 {
+        x: 1.0,
+        y: 2.0,
+    };
+    let v2 = Vector2 {
+        x: 3.0,
+        y: -4.0,
+    };
+
+    assert_eq!(v1.dot(&v2), -5.0);
+}
+#[test]
+fn dot_of_vector3() {
+    let v1 = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let v2 = Vector3 {
+        x: 3.0,
+        y: -4.0,
+        z: 5.0,
+    };
+
+    assert_eq!(v1.dot(&v2), 10.0);
+}
+#[test]
+fn dot_of_vector4() {
+    let v1 = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+    let v2 = Vector4 {
+        x: 3.0,
+        y: -4.0,
+        z: 5.0,
+        w: -6.0,
+    };
+
+    assert_eq!(v1.dot(&v2), -14.0);
+}
+
+#[test]
+fn length_of_vector3() {
+    let v = Vector3 {
+        x: 2.0,
+        y: 3.0,
+        z: 6.0,
+    };
+
+    assert_eq!(v.length_sq(), 49.0);
+    assert_eq!(v.length(), 7.0);
+}
+
+#[test]
+fn normalize_of_vector4() {
+    let v = Vector4 {
+        x: 0.0,
+        y: 3.0,
+        z: 0.0,
+        w: -4.0,
+    };
+    let n = v.normalize();
+
+    assert_eq!(n, Vector4 { x: 0.0, y: 0.6, z: 0.0, w: -0.8 });
+    assert_eq!(Vector4::zero().normalize(), Vector4::zero());
+}
+
+#[test]
+fn orthogonal_of_vector2() {
+    let v = Vector2 {
+        x: 1.5,
+        y: -2.5,
+    };
+    let o = v.orthogonal();
+
+    assert_eq!(o, Vector2 { x: 2.5, y: 1.5 });
+    assert_eq!(v.dot(&o), 0.0);
+}
+#[test]
+fn orthogonal_of_vector3() {
+    let vs = [
+        Vector3 { x: 1.0, y: 2.0, z: 3.0 },
+        Vector3 { x: 1.0, y: -2.0, z: 3.0 },
+        Vector3 { x: -1.0, y: 2.0, z: -3.0 },
+        Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+    ];
+
+    for v in vs.iter() {
+        let o = v.orthogonal();
+        assert!(o.length() > 0.0);
+        assert_eq!(v.dot(&o), 0.0);
+    }
+}
+#[test]
+fn orthogonal_of_vector3_with_y_and_z_of_different_signs() {
+    // XMVector3Orthogonal gives z = -x here, which is not orthogonal
+    let v = Vector3 { x: 1.0, y: -2.0, z: 3.0 };
+
+    assert_eq!(v.orthogonal(), Vector3 { x: -5.0, y: -1.0, z: 1.0 });
+}
+#[test]
+fn orthogonal_of_vector4() {
+    let v = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+    let o = v.orthogonal();
+
+    assert_eq!(o, Vector4 { x: 3.0, y: 4.0, z: -1.0, w: -2.0 });
+    assert_eq!(v.dot(&o), 0.0);
+}
+
+#[test]
+fn angle_between_normals_of_vector2() {
+    let v1 = Vector2 {
+        x: 1.0,
+        y: 0.0,
+    };
+    let v2 = Vector2 {
+        x: 0.0,
+        y: 1.0,
+    };
+
+    assert!((v1.angle_between_normals(&v2) - f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((v1.angle_between_normals(&-v1) - f32::consts::PI).abs() < 1e-6);
+    assert_eq!(v1.angle_between_normals(&v1), 0.0);
+}
+
+#[test]
+fn angle_between_normals_est_of_vector3() {
+    let v1 = Vector3 {
+        x: 1.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    let v2 = Vector3 {
+        x: 0.6,
+        y: 0.8,
+        z: 0.0,
+    };
+
+    let exact = v1.angle_between_normals(&v2);
+    let estimated = v1.angle_between_normals_est(&v2);
+    assert!((exact - estimated).abs() < 1e-4);
+    assert!((v1.angle_between_normals_est(&-v2) - (f32::consts::PI - exact)).abs() < 1e-4);
+}
+
+#[test]
+fn angle_between_vectors_of_vector3() {
+    let v1 = Vector3 {
+        x: 3.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    let v2 = Vector3 {
+        x: 0.0,
+        y: 5.0,
+        z: 5.0,
+    };
+    let v3 = Vector3 {
+        x: 0.0,
+        y: 0.0,
+        z: 2.0,
+    };
+
+    assert!((v1.angle_between_vectors(&v2) - f32::consts::FRAC_PI_2).abs() < 1e-6);
+    assert!((v2.angle_between_vectors(&v3) - f32::consts::FRAC_PI_4).abs() < 1e-6);
+}
+
+#[test]
+fn components_from_normal_of_vector3() {
+    let v = Vector3 {
+        x: 3.0,
+        y: 4.0,
+        z: 5.0,
+    };
+    let normal = Vector3 {
+        x: 0.0,
+        y: 1.0,
+        z: 0.0,
+    };
+    let (parallel, perpendicular) = v.components_from_normal(&normal);
+
+    assert_eq!(parallel, Vector3 { x: 0.0, y: 4.0, z: 0.0 });
+    assert_eq!(perpendicular, Vector3 { x: 3.0, y: 0.0, z: 5.0 });
+    assert_eq!(parallel + perpendicular, v);
+}
+
+#[test]
+fn clamp_length_of_vector2() {
+    let v = Vector2 {
+        x: 3.0,
+        y: 4.0,
+    };
+
+    assert_eq!(v.clamp_length(1.0, 2.5), Vector2 { x: 1.5, y: 2.0 });
+    assert_eq!(v.clamp_length(10.0, 20.0), Vector2 { x: 6.0, y: 8.0 });
+    assert_eq!(v.clamp_length(1.0, 10.0), v);
+    assert_eq!(Vector2::zero().clamp_length(1.0, 2.0), Vector2::zero());
+}
+
+#[test]
+fn clamp_length_v_of_vector4() {
+    let v = Vector4 {
+        x: 0.0,
+        y: 3.0,
+        z: 0.0,
+        w: 4.0,
+    };
+    let min = Vector4::replicate(1.0);
+    let max = Vector4::replicate(2.5);
+
+    assert_eq!(v.clamp_length_v(&min, &max), Vector4 { x: 0.0, y: 1.5, z: 0.0, w: 2.0 });
+}
+
+#[test]
+#[should_panic]
+fn clamp_length_v_of_vector3_should_fail_when_not_replicated() {
+    let v = Vector3::one();
+    let min = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 1.0,
+    };
+    let max = Vector3::replicate(3.0);
+
+    let _ = v.clamp_length_v(&min, &max);
+}
+
+#[test]
+fn cross_of_vector2() {
+    let v1 = Vector2 {
+        x: 1.0,
+        y: 2.0,
+    };
+    let v2 = Vector2 {
+        x: 3.0,
+        y: 4.0,
+    };
+
+    assert_eq!(v1.cross(&v2), -2.0);
+    assert_eq!(v2.cross(&v1), 2.0);
+}
+
+#[test]
+fn intersect_line_of_vector2() {
+    let result = Vector2::intersect_line(
+        &Vector2 { x: 0.0, y: 0.0 },
+        &Vector2 { x: 2.0, y: 2.0 },
+        &Vector2 { x: 0.0, y: 4.0 },
+        &Vector2 { x: 4.0, y: 0.0 },
+    );
+
+    assert_eq!(result, LineIntersection::Point(Vector2 { x: 2.0, y: 2.0 }));
+}
+
+#[test]
+fn intersect_line_of_vector2_outside_segments() {
+    let result = Vector2::intersect_line(
+        &Vector2 { x: 0.0, y: 1.0 },
+        &Vector2 { x: 1.0, y: 1.0 },
+        &Vector2 { x: 5.0, y: 0.0 },
+        &Vector2 { x: 5.0, y: -1.0 },
+    );
+
+    assert_eq!(result, LineIntersection::Point(Vector2 { x: 5.0, y: 1.0 }));
+}
+
+#[test]
+fn intersect_line_of_vector2_parallel() {
+    let result = Vector2::intersect_line(
+        &Vector2 { x: 0.0, y: 0.0 },
+        &Vector2 { x: 1.0, y: 1.0 },
+        &Vector2 { x: 0.0, y: 1.0 },
+        &Vector2 { x: 1.0, y: 2.0 },
+    );
+
+    assert_eq!(result, LineIntersection::Parallel);
+}
+
+#[test]
+fn intersect_line_of_vector2_coincident() {
+    let result = Vector2::intersect_line(
+        &Vector2 { x: 0.0, y: 0.0 },
+        &Vector2 { x: 1.0, y: 1.0 },
+        &Vector2 { x: 2.0, y: 2.0 },
+        &Vector2 { x: 3.0, y: 3.0 },
+    );
+
+    assert_eq!(result, LineIntersection::Coincident);
+}
+
+#[test]
+fn line_point_distance_of_vector2() {
+    let distance = Vector2::line_point_distance(
+        &Vector2 { x: -1.0, y: 1.0 },
+        &Vector2 { x: 1.0, y: 1.0 },
+        &Vector2 { x: 7.0, y: -2.0 },
+    );
+
+    assert_eq!(distance, 3.0);
+}
+
+#[test]
+fn line_point_distance_of_vector3() {
+    let distance = Vector3::line_point_distance(
+        &Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+        &Vector3 { x: 0.0, y: 0.0, z: 2.0 },
+        &Vector3 { x: 3.0, y: 4.0, z: -8.0 },
+    );
+
+    assert_eq!(distance, 5.0);
+    assert_eq!(Vector3::line_point_distance(
+        &Vector3 { x: 0.0, y: 0.0, z: 1.0 },
+        &Vector3 { x: 0.0, y: 0.0, z: 2.0 },
+        &Vector3 { x: 0.0, y: 0.0, z: 5.0 },
+    ), 0.0);
+}
+
+#[test]
+fn swizzle_of_vector2_checks_every_index() {
+    let v = Vector2 {
+        x: 1.32,
+        y: 23.3,
+    };
+
+    assert_eq!(v.swizzle(1, 0, 3, 2), Vector2 { x: 23.3, y: 1.32 });
+}
+
+#[test]
+#[should_panic]
+fn swizzle_of_vector2_should_fail_when_e3_is_more_than_3() {
+    let v = Vector2 {
+        x: 1.32,
+        y: 23.3,
+    };
+    let _ = v.swizzle(1, 0, 0, 4);
+}
+
+#[test]
+fn swizzle_accessors_of_vector2() {
+    let v = Vector2 {
+        x: 1.0,
+        y: 2.0,
+    };
+
+    assert_eq!(v.yx(), Vector2 { x: 2.0, y: 1.0 });
+    assert_eq!(v.xyx(), Vector3 { x: 1.0, y: 2.0, z: 1.0 });
+    assert_eq!(v.xxyy(), Vector4 { x: 1.0, y: 1.0, z: 2.0, w: 2.0 });
+}
+
+#[test]
+fn swizzle_accessors_of_vector3() {
+    let v = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+
+    assert_eq!(v.xy(), Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(v.zyx(), Vector3 { x: 3.0, y: 2.0, z: 1.0 });
+    assert_eq!(v.xyzz(), Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 3.0 });
+}
+
+#[test]
+fn swizzle_accessors_of_vector4() {
+    let v = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+
+    assert_eq!(v.wz(), Vector2 { x: 4.0, y: 3.0 });
+    assert_eq!(v.xyz(), Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+    assert_eq!(v.wzyx(), Vector4 { x: 4.0, y: 3.0, z: 2.0, w: 1.0 });
+    assert_eq!(v.xxyy(), Vector4 { x: 1.0, y: 1.0, z: 2.0, w: 2.0 });
+}
+
+#[test]
+fn shuffle_of_vector2() {
+    let v1 = Vector2 {
+        x: 1.0,
+        y: 2.0,
+    };
+    let v2 = Vector2 {
+        x: 3.0,
+        y: 4.0,
+    };
+
+    assert_eq!(v1.shuffle::<5, 0>(&v2), Vector2 { x: 4.0, y: 1.0 });
+    assert_eq!(v1.shuffle::<1, 4>(&v2), Vector2 { x: 2.0, y: 3.0 });
+}
+
+#[test]
+fn shuffle_of_vector3() {
+    let v1 = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let v2 = Vector3 {
+        x: 4.0,
+        y: 5.0,
+        z: 6.0,
+    };
+
+    assert_eq!(v1.shuffle::<6, 1, 4>(&v2), Vector3 { x: 6.0, y: 2.0, z: 4.0 });
+    assert_eq!(v1.shuffle::<5, 5, 2>(&v2), Vector3 { x: 5.0, y: 5.0, z: 3.0 });
+}
+
+#[test]
+fn shuffle_of_vector4() {
+    let v1 = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+    let v2 = Vector4 {
+        x: 5.0,
+        y: 6.0,
+        z: 7.0,
+        w: 8.0,
+    };
+
+    assert_eq!(v1.shuffle::<0, 5, 2, 7>(&v2), Vector4 { x: 1.0, y: 6.0, z: 3.0, w: 8.0 });
+    assert_eq!(v1.shuffle::<7, 6, 5, 4>(&v2), Vector4 { x: 8.0, y: 7.0, z: 6.0, w: 5.0 });
+}
+
+#[test]
+#[allow(deprecated)]
+fn permute_matches_shuffle() {
+    let v1 = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    let v2 = Vector4 { x: 5.0, y: 6.0, z: 7.0, w: 8.0 };
+
+    assert_eq!(v1.permute(&v2, 0, 5, 2, 7), v1.shuffle::<0, 5, 2, 7>(&v2));
+    assert_eq!(v1.xyz().permute(&v2.xyz(), 6, 1, 4, 0), v1.xyz().shuffle::<6, 1, 4>(&v2.xyz()));
+    assert_eq!(v1.xy().permute(&v2.xy(), 5, 1, 0, 0), v1.xy().shuffle::<5, 1>(&v2.xy()));
+}
+
+#[test]
+fn sign_mask_of_vector4() {
+    let v = Vector4::sign_mask();
+
+    assert!(v.x == 0.0 && v.x.is_sign_negative());
+    assert!(v.y == 0.0 && v.y.is_sign_negative());
+    assert!(v.z == 0.0 && v.z.is_sign_negative());
+    assert!(v.w == 0.0 && v.w.is_sign_negative());
+}
+
+#[test]
+fn get_and_set_of_vector3() {
+    let v = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+
+    assert_eq!(v.get_x(), 1.0);
+    assert_eq!(v.get_y(), 2.0);
+    assert_eq!(v.get_z(), 3.0);
+    assert_eq!(v.set_x(7.0), Vector3 { x: 7.0, y: 2.0, z: 3.0 });
+    assert_eq!(v.set_y(7.0), Vector3 { x: 1.0, y: 7.0, z: 3.0 });
+    assert_eq!(v.set_z(7.0), Vector3 { x: 1.0, y: 2.0, z: 7.0 });
+}
+
+#[test]
+fn get_and_set_of_vector4() {
+    let v = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+
+    assert_eq!(v.get_w(), 4.0);
+    assert_eq!(v.set_w(7.0), Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 7.0 });
+    assert_eq!(v.set_x(7.0).get_x(), 7.0);
+}
+
+#[test]
+fn rotate_of_vector2() {
+    let v = Vector2 {
+        x: 1.0,
+        y: 2.0,
+    };
+
+    assert_eq!(v.rotate_left::<0>(), v);
+    assert_eq!(v.rotate_left::<1>(), Vector2 { x: 2.0, y: 1.0 });
+    assert_eq!(v.rotate_right::<1>(), Vector2 { x: 2.0, y: 1.0 });
+}
+
+#[test]
+fn rotate_of_vector3() {
+    let v = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+
+    assert_eq!(v.rotate_left::<1>(), Vector3 { x: 2.0, y: 3.0, z: 1.0 });
+    assert_eq!(v.rotate_right::<1>(), Vector3 { x: 3.0, y: 1.0, z: 2.0 });
+    assert_eq!(v.rotate_left::<2>().rotate_right::<2>(), v);
+}
+
+#[test]
+fn rotate_of_vector4() {
+    let v = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+
+    assert_eq!(v.rotate_left::<1>(), Vector4 { x: 2.0, y: 3.0, z: 4.0, w: 1.0 });
+    assert_eq!(v.rotate_left::<3>(), Vector4 { x: 4.0, y: 1.0, z: 2.0, w: 3.0 });
+    assert_eq!(v.rotate_right::<1>(), Vector4 { x: 4.0, y: 1.0, z: 2.0, w: 3.0 });
+    assert_eq!(v.rotate_right::<0>(), v);
+}
+
+#[test]
+fn shift_left_of_vector3() {
+    let v1 = Vector3 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+    };
+    let v2 = Vector3 {
+        x: 4.0,
+        y: 5.0,
+        z: 6.0,
+    };
+
+    assert_eq!(v1.shift_left::<0>(&v2), v1);
+    assert_eq!(v1.shift_left::<2>(&v2), Vector3 { x: 3.0, y: 4.0, z: 5.0 });
+}
+
+#[test]
+fn shift_left_of_vector4() {
+    let v1 = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+    let v2 = Vector4 {
+        x: 5.0,
+        y: 6.0,
+        z: 7.0,
+        w: 8.0,
+    };
+
+    assert_eq!(v1.shift_left::<1>(&v2), Vector4 { x: 2.0, y: 3.0, z: 4.0, w: 5.0 });
+    assert_eq!(v1.shift_left::<3>(&v2), Vector4 { x: 4.0, y: 5.0, z: 6.0, w: 7.0 });
+}
+
+#[test]
+fn insert_of_vector4() {
+    let destination = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+    let source = Vector4 {
+        x: 5.0,
+        y: 6.0,
+        z: 7.0,
+        w: 8.0,
+    };
+
+    assert_eq!(destination.insert::<0, false, true, false, true>(&source), Vector4 { x: 1.0, y: 6.0, z: 3.0, w: 8.0 });
+    assert_eq!(destination.insert::<2, true, false, false, true>(&source), Vector4 { x: 7.0, y: 2.0, z: 3.0, w: 6.0 });
+}
+
+#[test]
+fn merge_of_vector4() {
+    let v1 = Vector4 {
+        x: 1.0,
+        y: 2.0,
+        z: 3.0,
+        w: 4.0,
+    };
+    let v2 = Vector4 {
+        x: 5.0,
+        y: 6.0,
+        z: 7.0,
+        w: 8.0,
+    };
+
+    assert_eq!(v1.merge_xy(&v2), Vector4 { x: 1.0, y: 5.0, z: 2.0, w: 6.0 });
+    assert_eq!(v1.merge_zw(&v2), Vector4 { x: 3.0, y: 7.0, z: 4.0, w: 8.0 });
+}
+
+fn primes() -> Matrix {
+    Matrix::new(
+        2.0, 3.0, 5.0, 7.0,
+        11.0, 13.0, 17.0, 19.0,
+        23.0, 29.0, 31.0, 37.0,
+        41.0, 43.0, 47.0, 53.0,
+    )
+}
+
+#[test]
+fn mul_matrix_of_vector2() {
+    let v = Vector2 { x: 2.0, y: -1.0 };
+    let m = primes();
+    let rv = &v;
+
+    assert_eq!(v * &m, v.transform(&m));
+    assert_eq!(rv * &m, v.transform(&m));
+    assert_eq!(rv * m.clone(), v.transform(&m));
+    assert_eq!(v * m.clone(), v.transform(&m));
+}
+
+#[test]
+fn mul_matrix_of_vector3() {
+    let v = Vector3 { x: 2.0, y: -1.0, z: 0.5 };
+    let m = primes();
+    let rv = &v;
+
+    assert_eq!(v * &m, v.transform(&m));
+    assert_eq!(rv * m.clone(), v.transform(&m));
+
+    let mut w = v;
+    w *= &m;
+    assert_eq!(w, v.transform(&m));
+}
+
+#[test]
+fn mul_matrix_of_vector4() {
+    let v = Vector4 { x: 2.0, y: -1.0, z: 0.5, w: 1.0 };
+    let m = Matrix::translation(1.0, 2.0, 3.0);
+
+    assert_eq!(v * &m, Vector4 { x: 3.0, y: 1.0, z: 3.5, w: 1.0 });
+    assert_eq!(v * Matrix::identity() * m.clone(), v * m);
+}
+
+#[test]
+fn scalar_mul_and_div_of_vector2() {
+    let v = Vector2 { x: 2.0, y: -4.0 };
+
+    assert_eq!(2.0 * v, Vector2 { x: 4.0, y: -8.0 });
+    assert_eq!(2.0 * &v, v * 2.0);
+    assert_eq!(v / 2.0, Vector2 { x: 1.0, y: -2.0 });
+    assert_eq!(&v / 4.0, Vector2 { x: 0.5, y: -1.0 });
+}
+
+#[test]
+fn scalar_mul_and_div_of_vector3() {
+    let v = Vector3 { x: 2.0, y: -4.0, z: 6.0 };
+
+    assert_eq!(0.5 * v, Vector3 { x: 1.0, y: -2.0, z: 3.0 });
+    assert_eq!(&v * 3.0, 3.0 * v);
+    assert_eq!(v / 2.0, Vector3 { x: 1.0, y: -2.0, z: 3.0 });
+    assert!((v / 0.0).x.is_infinite());
+}
+
+#[test]
+fn scalar_mul_and_div_of_vector4() {
+    let v = Vector4 { x: 2.0, y: -4.0, z: 6.0, w: 8.0 };
+
+    assert_eq!(2.0 * v, Vector4 { x: 4.0, y: -8.0, z: 12.0, w: 16.0 });
+    assert_eq!(v / 2.0, Vector4 { x: 1.0, y: -2.0, z: 3.0, w: 4.0 });
+}
+
+#[test]
+fn reference_operators() {
+    let a = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+    let b = Vector3 { x: 4.0, y: 5.0, z: 6.0 };
+    let (ra, rb) = (&a, &b);
+
+    assert_eq!(ra + rb, a + b);
+    assert_eq!(a + rb, a + b);
+    assert_eq!(ra + b, a + b);
+    assert_eq!(ra - rb, a - b);
+    assert_eq!(ra * rb, a * b);
+    assert_eq!(ra / b, a / b);
+    assert_eq!(-ra, -a);
+}
+
+#[test]
+fn assign_operators_of_vector2() {
+    let mut v = Vector2 { x: 1.0, y: 2.0 };
+    v += Vector2 { x: 1.0, y: 1.0 };
+    assert_eq!(v, Vector2 { x: 2.0, y: 3.0 });
+    v -= &Vector2 { x: 2.0, y: 0.0 };
+    assert_eq!(v, Vector2 { x: 0.0, y: 3.0 });
+    v *= 2.0;
+    assert_eq!(v, Vector2 { x: 0.0, y: 6.0 });
+    v /= 3.0;
+    assert_eq!(v, Vector2 { x: 0.0, y: 2.0 });
+}
+
+#[test]
+fn assign_operators_of_vector4() {
+    let mut v = Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    v *= Vector4 { x: 2.0, y: 2.0, z: 2.0, w: 2.0 };
+    assert_eq!(v, Vector4 { x: 2.0, y: 4.0, z: 6.0, w: 8.0 });
+    v /= &Vector4 { x: 2.0, y: 4.0, z: 6.0, w: 8.0 };
+    assert_eq!(v, Vector4::one());
+    v *= Matrix::translation(1.0, 0.0, 0.0);
+    assert_eq!(v, Vector4 { x: 2.0, y: 1.0, z: 1.0, w: 1.0 });
+}
+
+#[test]
+fn sum_of_vectors() {
+    let vs = [
+        Vector3 { x: 1.0, y: 2.0, z: 3.0 },
+        Vector3 { x: 4.0, y: 5.0, z: 6.0 },
+        Vector3 { x: -1.0, y: 0.0, z: 1.0 },
+    ];
+
+    assert_eq!(vs.iter().sum::<Vector3>(), Vector3 { x: 4.0, y: 7.0, z: 10.0 });
+    assert_eq!(vs.iter().cloned().sum::<Vector3>(), Vector3 { x: 4.0, y: 7.0, z: 10.0 });
+    assert_eq!(Vec::<Vector2>::new().into_iter().sum::<Vector2>(), Vector2::zero());
+    assert_eq!([Vector4::one(), Vector4::one()].iter().sum::<Vector4>(), Vector4::replicate(2.0));
+}
@@ -0,0 +1,107 @@
+extern crate xmath;
+
+use xmath::{Vector3, Matrix, Viewport};
+
+fn assert_near(v1: &Vector3, v2: &Vector3, epsilon: f32) {
+    assert!((v1.x - v2.x).abs() < epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.y - v2.y).abs() < epsilon, "{:?} != {:?}", v1, v2);
+    assert!((v1.z - v2.z).abs() < epsilon, "{:?} != {:?}", v1, v2);
+}
+
+fn viewport() -> Viewport {
+    Viewport::new(10.0, 20.0, 800.0, 600.0, 0.0, 1.0)
+}
+
+fn projection() -> Matrix {
+    Matrix::perspective_fov(1.0, 800.0 / 600.0, 0.1, 100.0)
+}
+
+fn view() -> Matrix {
+    Matrix::rotation_y(0.3) * Matrix::translation(0.5, -1.0, -10.0)
+}
+
+fn world() -> Matrix {
+    Matrix::rotation_z(1.1) * Matrix::translation(2.0, 1.0, 0.0)
+}
+
+#[test]
+fn new_viewport() {
+    let viewport = viewport();
+
+    assert_eq!(viewport.x, 10.0);
+    assert_eq!(viewport.y, 20.0);
+    assert_eq!(viewport.width, 800.0);
+    assert_eq!(viewport.height, 600.0);
+    assert_eq!(viewport.min_z, 0.0);
+    assert_eq!(viewport.max_z, 1.0);
+}
+
+#[test]
+fn project_point_on_view_axis_to_viewport_center() {
+    let identity = Matrix::identity();
+    let v = Vector3 {
+        x: 0.0,
+        y: 0.0,
+        z: -5.0,
+    };
+
+    let projected = v.project(&viewport(), &projection(), &identity, &identity);
+
+    assert_near(&projected, &Vector3 { x: 410.0, y: 320.0, z: projected.z }, 1e-3);
+    assert!(0.0 < projected.z && projected.z < 1.0);
+}
+
+#[test]
+fn project_near_and_far_planes_to_depth_range() {
+    let identity = Matrix::identity();
+    let viewport = Viewport::new(0.0, 0.0, 100.0, 100.0, 0.25, 0.75);
+    let near = Vector3 { x: 0.0, y: 0.0, z: -0.1 };
+    let far = Vector3 { x: 0.0, y: 0.0, z: -100.0 };
+
+    assert!((near.project(&viewport, &projection(), &identity, &identity).z - 0.25).abs() < 1e-5);
+    assert!((far.project(&viewport, &projection(), &identity, &identity).z - 0.75).abs() < 1e-5);
+}
+
+#[test]
+fn project_then_unproject_is_identity() {
+    let points = [
+        Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+        Vector3 { x: 1.0, y: 2.0, z: 3.0 },
+        Vector3 { x: -2.5, y: 0.5, z: -1.0 },
+    ];
+
+    for v in points.iter() {
+        let projected = v.project(&viewport(), &projection(), &view(), &world());
+        let unprojected = projected.unproject(&viewport(), &projection(), &view(), &world());
+        assert_near(&unprojected, v, 1e-3);
+    }
+}
+
+#[test]
+fn project_stream_then_unproject_stream_is_identity() {
+    let points = [
+        Vector3 { x: 0.0, y: 0.0, z: 0.0 },
+        Vector3 { x: 1.0, y: 2.0, z: 3.0 },
+        Vector3 { x: -2.5, y: 0.5, z: -1.0 },
+    ];
+    let mut projected = [Vector3 { x: 0.0, y: 0.0, z: 0.0 }; 3];
+    let mut unprojected = [Vector3 { x: 0.0, y: 0.0, z: 0.0 }; 3];
+
+    Vector3::project_stream(&mut projected, &points, &viewport(), &projection(), &view(), &world());
+    Vector3::unproject_stream(&mut unprojected, &projected, &viewport(), &projection(), &view(), &world());
+
+    for i in 0..3 {
+        assert_eq!(projected[i], points[i].project(&viewport(), &projection(), &view(), &world()));
+        assert_near(&unprojected[i], &points[i], 1e-3);
+    }
+}
+
+#[test]
+#[should_panic]
+fn project_stream_should_fail_when_lengths_differ() {
+    let identity = Matrix::identity();
+    let points = [Vector3 { x: 0.0, y: 0.0, z: 0.0 }; 3];
+    let mut projected = [Vector3 { x: 0.0, y: 0.0, z: 0.0 }; 2];
+
+    Vector3::project_stream(&mut projected, &points, &viewport(), &projection(), &identity, &identity);
+}